@@ -0,0 +1,354 @@
+//! BIP-32 hierarchical deterministic keys
+//!
+//! The master key is HMAC-SHA512 keyed with "Bitcoin seed" over the wallet
+//! seed: for SLIP-39 the seed is the master secret itself, for BIP-39 it is the
+//! PBKDF2 seed of the mnemonic. The fingerprint of the master key (the first four
+//! bytes of HASH160 of its public key) identifies the wallet without revealing
+//! any key, and an xpub at an account path can be compared with the one shown
+//! by a wallet. Only private derivation is implemented.
+//!
+//! Curve arithmetic uses `k256`, HASH160 uses `ripemd` and `sha2`, and extended
+//! keys are serialized with `bs58`.
+
+use crate::Error;
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{Scalar, SecretKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt;
+use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Offset of hardened child indices
+pub const HARDENED: u32 = 1 << 31;
+
+/// Length of a key fingerprint in bytes
+pub const FINGERPRINT_LENGTH: usize = 4;
+
+/// Length of a compressed public key in bytes
+pub const PUBLIC_KEY_LENGTH: usize = 33;
+
+/// HMAC key of the master key derivation
+const MASTER_KEY_SALT: &[u8] = b"Bitcoin seed";
+
+/// Allowed seed lengths in bytes (128 to 512 bits)
+const SEED_LENGTHS: std::ops::RangeInclusive<usize> = 16..=64;
+
+/// Version bytes of mainnet extended keys
+const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
+/// A derivation path such as `m/84'/0'/0'`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// The child indices from the master key, hardened ones offset by [`HARDENED`]
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parse a path such as `m/84'/0'/0'/0/5`; `h` or `H` also mark hardened indices
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidDerivationPath(s.to_string());
+        let trimmed = s.trim();
+        let rest = match trimmed.strip_prefix('m') {
+            Some("") => return Ok(DerivationPath::default()),
+            Some(rest) => rest.strip_prefix('/').ok_or_else(invalid)?,
+            None => trimmed,
+        };
+
+        rest.split('/')
+            .map(|component| {
+                let (number, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                    Some(number) => (number, HARDENED),
+                    None => (component, 0),
+                };
+                match number.parse::<u32>() {
+                    Ok(index) if index < HARDENED && !number.starts_with('+') => {
+                        Ok(index | hardened)
+                    }
+                    _ => Err(invalid()),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(DerivationPath)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for &index in &self.0 {
+            if index >= HARDENED {
+                write!(f, "/{}'", index - HARDENED)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An extended private key: a secp256k1 secret key and its chain code
+///
+/// The secret key and chain code are private, redacted from `Debug` output and
+/// zeroized on drop; [`ExtendedPrivateKey::to_xprv`] is the only way to export them.
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    /// Number of derivations from the master key
+    pub depth: u8,
+    /// Fingerprint of the parent key (zero for the master key)
+    pub parent_fingerprint: [u8; FINGERPRINT_LENGTH],
+    /// Index of this key in its parent (zero for the master key)
+    pub child_number: u32,
+    chain_code: [u8; 32],
+    secret_key: SecretKey,
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &hex::encode(self.parent_fingerprint))
+            .field("child_number", &self.child_number)
+            .field("fingerprint", &hex::encode(self.fingerprint()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        // `SecretKey` zeroizes itself
+        self.chain_code.zeroize();
+    }
+}
+
+/// HMAC-SHA512, split into the key (left) and chain code (right) halves
+fn hmac_sha512(key: &[u8], data: &[u8]) -> (Zeroizing<[u8; 32]>, [u8; 32]) {
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    mac.update(data);
+    let mut output = mac.finalize().into_bytes();
+    let mut left = Zeroizing::new([0u8; 32]);
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+    output.as_mut_slice().zeroize();
+    (left, right)
+}
+
+impl ExtendedPrivateKey {
+    /// Derive the master key of a seed
+    ///
+    /// # Arguments
+    /// * `seed` - 16 to 64 bytes: a SLIP-39 master secret or a BIP-39 seed
+    ///
+    /// # Returns
+    /// * `Ok(ExtendedPrivateKey)` - The master key
+    /// * `Err(Error::InvalidParameters)` - If the seed length is not allowed
+    /// * `Err(Error::InvalidDerivedKey)` - If the seed yields an invalid key (probability below 2^-127)
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        if !SEED_LENGTHS.contains(&seed.len()) {
+            return Err(Error::InvalidParameters(format!(
+                "BIP-32 seed must be 16 to 64 bytes, got {} bytes",
+                seed.len()
+            )));
+        }
+        let (key, chain_code) = hmac_sha512(MASTER_KEY_SALT, seed);
+        let secret_key = SecretKey::from_slice(key.as_slice())
+            .map_err(|_| Error::InvalidDerivedKey(DerivationPath::default().to_string()))?;
+        Ok(ExtendedPrivateKey {
+            depth: 0,
+            parent_fingerprint: [0; FINGERPRINT_LENGTH],
+            child_number: 0,
+            chain_code,
+            secret_key,
+        })
+    }
+
+    /// Derive a child key (indices from [`HARDENED`] up are hardened)
+    ///
+    /// # Returns
+    /// * `Ok(ExtendedPrivateKey)` - The child key
+    /// * `Err(Error::InvalidDerivedKey)` - If the index yields an invalid key (probability below 2^-127)
+    /// * `Err(Error::InvalidParameters)` - If the key is already at depth 255
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        let depth = self.depth.checked_add(1).ok_or_else(|| {
+            Error::InvalidParameters("derivation paths are limited to 255 levels".to_string())
+        })?;
+
+        let mut data = Zeroizing::new(Vec::with_capacity(PUBLIC_KEY_LENGTH + 4));
+        if index >= HARDENED {
+            data.push(0);
+            data.extend_from_slice(&Zeroizing::new(self.secret_key.to_bytes()));
+        } else {
+            data.extend_from_slice(&self.public_key());
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let invalid = || {
+            Error::InvalidDerivedKey(format!(
+                "child {} of key {}",
+                index,
+                hex::encode(self.fingerprint())
+            ))
+        };
+        let (tweak, chain_code) = hmac_sha512(&self.chain_code, &data);
+        // The tweak must be below the curve order and the sum must not be zero
+        let tweak =
+            Option::<Scalar>::from(Scalar::from_repr((*tweak).into())).ok_or_else(invalid)?;
+        let sum = Zeroizing::new(tweak + self.secret_key.to_nonzero_scalar().as_ref());
+        let secret_key = SecretKey::from_bytes(&sum.to_bytes()).map_err(|_| invalid())?;
+        Ok(ExtendedPrivateKey {
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            secret_key,
+        })
+    }
+
+    /// Derive the key at a path from this key
+    ///
+    /// # Returns
+    /// See [`ExtendedPrivateKey::derive_child`]
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.indices()
+            .iter()
+            .try_fold(self.clone(), |key, &index| key.derive_child(index))
+    }
+
+    /// Compressed secp256k1 public key
+    pub fn public_key(&self) -> [u8; PUBLIC_KEY_LENGTH] {
+        let point = self.secret_key.public_key().to_encoded_point(true);
+        let mut key = [0u8; PUBLIC_KEY_LENGTH];
+        key.copy_from_slice(point.as_bytes());
+        key
+    }
+
+    /// First four bytes of HASH160 (RIPEMD-160 of SHA-256) of the public key
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_LENGTH] {
+        let hash = Ripemd160::digest(Sha256::digest(self.public_key()));
+        let mut fingerprint = [0u8; FINGERPRINT_LENGTH];
+        fingerprint.copy_from_slice(&hash[..FINGERPRINT_LENGTH]);
+        fingerprint
+    }
+
+    /// Base58Check serialization with the given version and key data
+    fn serialize(&self, version: [u8; 4], key: &[u8; PUBLIC_KEY_LENGTH]) -> String {
+        let mut payload = Zeroizing::new(Vec::with_capacity(78));
+        payload.extend_from_slice(&version);
+        payload.push(self.depth);
+        payload.extend_from_slice(&self.parent_fingerprint);
+        payload.extend_from_slice(&self.child_number.to_be_bytes());
+        payload.extend_from_slice(&self.chain_code);
+        payload.extend_from_slice(key);
+        bs58::encode(payload.as_slice()).with_check().into_string()
+    }
+
+    /// Serialized extended private key (`xprv...`)
+    pub fn to_xprv(&self) -> String {
+        let mut key = Zeroizing::new([0u8; PUBLIC_KEY_LENGTH]);
+        key[1..].copy_from_slice(&Zeroizing::new(self.secret_key.to_bytes()));
+        self.serialize(XPRV_VERSION, &key)
+    }
+
+    /// Serialized extended public key (`xpub...`)
+    pub fn to_xpub(&self) -> String {
+        self.serialize(XPUB_VERSION, &self.public_key())
+    }
+}
+
+/// Check that a seed restores the wallet with the given master key fingerprint
+///
+/// # Arguments
+/// * `seed` - A SLIP-39 master secret or a BIP-39 seed
+/// * `expected` - The master key fingerprint shown by the wallet
+///
+/// # Returns
+/// * `Ok(())` - If the fingerprints match
+/// * `Err(Error::FingerprintMismatch)` - If the seed belongs to another wallet
+/// * `Err(Error)` - See [`ExtendedPrivateKey::from_seed`]
+pub fn verify_fingerprint(seed: &[u8], expected: &[u8; FINGERPRINT_LENGTH]) -> Result<(), Error> {
+    let actual = ExtendedPrivateKey::from_seed(seed)?.fingerprint();
+    if &actual == expected {
+        Ok(())
+    } else {
+        Err(Error::FingerprintMismatch(
+            hex::encode(expected),
+            hex::encode(actual),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_derivation_path() {
+        let path: DerivationPath = "m/84'/0h/0H/1/7".parse().unwrap();
+        assert_eq!(path.indices(), [84 + HARDENED, HARDENED, HARDENED, 1, 7]);
+        assert_eq!(path.to_string(), "m/84'/0'/0'/1/7");
+        assert_eq!("m".parse::<DerivationPath>().unwrap().indices(), []);
+        assert_eq!(
+            "44'/0'".parse::<DerivationPath>().unwrap().to_string(),
+            "m/44'/0'"
+        );
+
+        for invalid in [
+            "",
+            "m/",
+            "m//1",
+            "m/x",
+            "m/2147483648",
+            "m/1''",
+            "n/1",
+            "m/+1",
+        ] {
+            assert!(
+                matches!(
+                    invalid.parse::<DerivationPath>(),
+                    Err(Error::InvalidDerivationPath(_))
+                ),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_fingerprint() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        assert!(verify_fingerprint(&seed, &[0x34, 0x42, 0x19, 0x3e]).is_ok());
+        assert!(matches!(
+            verify_fingerprint(&seed, &[0x34, 0x42, 0x19, 0x3f]),
+            Err(Error::FingerprintMismatch(expected, actual))
+                if expected == "3442193f" && actual == "3442193e"
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let key = ExtendedPrivateKey::from_seed(&seed).unwrap();
+        let debug = format!("{:?}", key);
+        assert!(debug.contains("3442193e"), "{}", debug);
+        assert!(!debug.contains(&hex::encode(key.secret_key.to_bytes())));
+        assert!(!debug.contains(&hex::encode(key.chain_code)));
+    }
+
+    #[test]
+    fn test_rejects_seed_length() {
+        assert!(ExtendedPrivateKey::from_seed(&[0; 15]).is_err());
+        assert!(ExtendedPrivateKey::from_seed(&[0; 65]).is_err());
+        assert!(ExtendedPrivateKey::from_seed(&[0; 16]).is_ok());
+    }
+}
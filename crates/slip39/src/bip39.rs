@@ -0,0 +1,390 @@
+//! BIP-39 mnemonics
+//!
+//! BIP-39 encodes 128 to 256 bits of entropy as 12 to 24 words of 11 bits from
+//! a 2048-word list. The last word carries a checksum made of the first
+//! `ENT / 32` bits of SHA-256 over the entropy. The wallet seed is derived from
+//! the mnemonic sentence with PBKDF2-HMAC-SHA512.
+//!
+//! Every word of the English list is unique by its first four letters, like the
+//! SLIP-39 list, so abbreviations and unique prefixes are accepted as well.
+
+use crate::Error;
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Number of bits encoded by each word
+pub const RADIX_BITS: usize = 11;
+
+/// Number of words in the wordlist
+pub const WORD_COUNT: usize = 1 << RADIX_BITS;
+
+/// PBKDF2 iterations of the seed derivation
+pub const SEED_ITERATION_COUNT: u32 = 2048;
+
+/// Length of the derived seed in bytes
+pub const SEED_LENGTH: usize = 64;
+
+/// Salt prefix of the seed derivation, followed by the passphrase
+const SALT_PREFIX: &str = "mnemonic";
+
+/// SHA256 checksum of the official English wordlist (const/bip39_english.txt)
+/// Official source: https://github.com/bitcoin/bips/blob/master/bip-0039/english.txt
+/// If the checksum test fails, restore the file from git or, if the update was
+/// intentional, update this constant via `shasum -a 256 const/bip39_english.txt`
+pub const WORDLIST_SHA256: &str =
+    "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda";
+
+/// The English BIP-39 wordlist (2048 words), loaded lazily on first access
+static WORDLIST: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+/// Position of every word in the wordlist, built on first access
+static WORD_INDICES: OnceLock<HashMap<&'static str, u16>> = OnceLock::new();
+
+/// Get the English BIP-39 wordlist, initializing it if necessary
+pub fn wordlist() -> &'static [&'static str] {
+    WORDLIST.get_or_init(|| {
+        include_str!("../const/bip39_english.txt")
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.trim())
+            .collect()
+    })
+}
+
+/// Get the word -> index map, initializing it if necessary
+fn word_indices() -> &'static HashMap<&'static str, u16> {
+    WORD_INDICES.get_or_init(|| {
+        wordlist()
+            .iter()
+            .enumerate()
+            .map(|(index, &word)| (word, index as u16))
+            .collect()
+    })
+}
+
+/// Get the index (0-2047) of a word (case-insensitive, trimmed)
+///
+/// # Returns
+/// * `Ok(u16)` - The position of the word in the wordlist
+/// * `Err(Error::Bip39WordNotFound)` - If word is not in the wordlist
+pub fn word_to_index(word: &str) -> Result<u16, Error> {
+    word_indices()
+        .get(word.trim().to_lowercase().as_str())
+        .copied()
+        .ok_or_else(|| Error::Bip39WordNotFound(word.to_string()))
+}
+
+/// Get the index (0-2047) of a word by its abbreviation or any other unique prefix
+///
+/// A full word is always accepted, even when it is also the prefix of another word.
+///
+/// # Returns
+/// * `Ok(u16)` - The position of the only matching word
+/// * `Err(Error::Bip39WordNotFound)` - If no words start with prefix
+/// * `Err(Error::AmbiguousPrefix)` - If multiple words start with prefix
+pub fn find_by_prefix(prefix: &str) -> Result<u16, Error> {
+    let normalized = prefix.trim().to_lowercase();
+    if let Some(&index) = word_indices().get(normalized.as_str()) {
+        return Ok(index);
+    }
+
+    // The wordlist is sorted, so the matches form a contiguous range
+    let words = wordlist();
+    let start = words.partition_point(|&word| word < normalized.as_str());
+    let matches: Vec<&str> = words[start..]
+        .iter()
+        .take_while(|word| word.starts_with(&normalized))
+        .copied()
+        .collect();
+
+    match matches.len() {
+        0 => Err(Error::Bip39WordNotFound(prefix.to_string())),
+        1 => Ok(start as u16),
+        count => {
+            let suffix = if count > 3 { ", ..." } else { "" };
+            Err(Error::AmbiguousPrefix(
+                prefix.to_string(),
+                count,
+                format!("{}{}", matches[..count.min(3)].join(", "), suffix),
+            ))
+        }
+    }
+}
+
+/// Get a word from the wordlist by its index (0-2047)
+///
+/// # Returns
+/// * `Ok(&str)` - The word at the given index
+/// * `Err(Error::Bip39IndexOutOfRange)` - If index is > 2047
+pub fn index_to_word(index: usize) -> Result<&'static str, Error> {
+    wordlist()
+        .get(index)
+        .copied()
+        .ok_or(Error::Bip39IndexOutOfRange(index))
+}
+
+/// Parse a decimal word index (e.g. "2047")
+///
+/// # Returns
+/// * `Ok(u16)` - The index
+/// * `Err(Error::InvalidBip39Index)` - If the input is not a number
+/// * `Err(Error::Bip39IndexOutOfRange)` - If index is > 2047
+pub fn parse_index(input: &str) -> Result<u16, Error> {
+    let index: usize = input
+        .trim()
+        .parse()
+        .map_err(|_| Error::InvalidBip39Index(input.to_string()))?;
+    index_to_word(index).map(|_| index as u16)
+}
+
+/// Encode a BIP-39 word to its 11-bit binary representation
+///
+/// # Example
+/// ```
+/// use slip39_calculator::bip39;
+///
+/// assert_eq!(bip39::encode("ability").unwrap(), "00000000001");
+/// ```
+pub fn encode(word: &str) -> Result<String, Error> {
+    word_to_index(word).map(|index| format!("{:011b}", index))
+}
+
+/// Decode an 11-bit binary string to its BIP-39 word
+///
+/// # Returns
+/// * `Ok(String)` - The corresponding BIP-39 word
+/// * `Err(Error::InvalidBip39BinaryLength)` - If the string is not exactly 11 characters
+/// * `Err(Error::InvalidBinary)` - If the string contains characters other than '0' and '1'
+pub fn decode(binary: &str) -> Result<String, Error> {
+    if binary.len() != RADIX_BITS {
+        return Err(Error::InvalidBip39BinaryLength(binary.len()));
+    }
+    if !binary.chars().all(|c| c == '0' || c == '1') {
+        return Err(Error::InvalidBinary(
+            "Binary string must only contain '0' and '1'".to_string(),
+        ));
+    }
+    let index =
+        usize::from_str_radix(binary, 2).map_err(|e| Error::InvalidBinary(e.to_string()))?;
+    index_to_word(index).map(str::to_string)
+}
+
+/// Reject entropy lengths other than 128 to 256 bits in steps of 32 bits
+fn validate_entropy_length(entropy: &[u8]) -> Result<(), Error> {
+    if (16..=32).contains(&entropy.len()) && entropy.len().is_multiple_of(4) {
+        Ok(())
+    } else {
+        Err(Error::InvalidEntropyLength(entropy.len() * 8))
+    }
+}
+
+/// Entropy bits followed by the checksum bits, as 11-bit word indices
+fn entropy_to_indices(entropy: &[u8]) -> Vec<u16> {
+    let checksum_bits = entropy.len() / 4;
+    let hash = Sha256::digest(entropy);
+    let bits = entropy
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1))
+        .chain((0..checksum_bits).map(|i| (hash[i / 8] >> (7 - i % 8)) & 1))
+        .collect::<Vec<u8>>();
+    bits.chunks(RADIX_BITS)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0u16, |acc, &bit| acc << 1 | u16::from(bit))
+        })
+        .collect()
+}
+
+/// Encode entropy as a BIP-39 mnemonic
+///
+/// # Arguments
+/// * `entropy` - 16, 20, 24, 28 or 32 bytes
+///
+/// # Returns
+/// * `Ok(String)` - 12 to 24 words separated by single spaces
+/// * `Err(Error::InvalidEntropyLength)` - If the entropy length is not allowed
+///
+/// # Example
+/// ```
+/// use slip39_calculator::bip39;
+///
+/// let mnemonic = bip39::entropy_to_mnemonic(&[0u8; 16]).unwrap();
+/// assert!(mnemonic.starts_with("abandon abandon"));
+/// assert!(mnemonic.ends_with("abandon about"));
+/// ```
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String, Error> {
+    validate_entropy_length(entropy)?;
+    Ok(entropy_to_indices(entropy)
+        .into_iter()
+        .map(|index| wordlist()[usize::from(index)])
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+/// Convert a whitespace-separated mnemonic to its word indices
+///
+/// # Arguments
+/// * `mnemonic` - BIP-39 words, abbreviations or unique prefixes (case-insensitive)
+///
+/// # Returns
+/// * `Ok(Vec<u16>)` - The index (0-2047) of every word, in order
+/// * `Err(Error)` - If a word is unknown or an ambiguous prefix
+pub fn mnemonic_to_indices(mnemonic: &str) -> Result<Vec<u16>, Error> {
+    mnemonic.split_whitespace().map(find_by_prefix).collect()
+}
+
+/// Recover the entropy of a mnemonic, verifying its checksum
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The entropy (16 to 32 bytes)
+/// * `Err(Error::InvalidBip39MnemonicLength)` - If the mnemonic does not have 12, 15, 18, 21 or 24 words
+/// * `Err(Error::InvalidBip39Checksum)` - If the checksum bits do not match the entropy
+/// * `Err(Error)` - If a word is unknown or an ambiguous prefix
+pub fn mnemonic_to_entropy(mnemonic: &str) -> Result<Vec<u8>, Error> {
+    indices_to_entropy(&mnemonic_to_indices(mnemonic)?)
+}
+
+/// Recover the entropy from word indices, verifying the checksum
+///
+/// # Returns
+/// See [`mnemonic_to_entropy`]
+pub fn indices_to_entropy(indices: &[u16]) -> Result<Vec<u8>, Error> {
+    if !(12..=24).contains(&indices.len()) || !indices.len().is_multiple_of(3) {
+        return Err(Error::InvalidBip39MnemonicLength(indices.len()));
+    }
+    if let Some(&index) = indices.iter().find(|&&i| usize::from(i) >= WORD_COUNT) {
+        return Err(Error::Bip39IndexOutOfRange(usize::from(index)));
+    }
+
+    // Every 3 words hold 32 bits of entropy and 1 bit of checksum
+    let entropy_bytes = indices.len() / 3 * 4;
+    let bits: Vec<u8> = indices
+        .iter()
+        .flat_map(|&index| (0..RADIX_BITS).rev().map(move |i| (index >> i) as u8 & 1))
+        .collect();
+    let entropy: Vec<u8> = bits[..entropy_bytes * 8]
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| acc << 1 | bit))
+        .collect();
+
+    if entropy_to_indices(&entropy) != indices {
+        return Err(Error::InvalidBip39Checksum);
+    }
+    Ok(entropy)
+}
+
+/// Derive the 512-bit wallet seed of a mnemonic
+///
+/// The mnemonic is validated, then normalized to lowercase full words separated
+/// by single spaces. BIP-39 applies Unicode NFKD normalization to the sentence
+/// and passphrase, which leaves printable ASCII unchanged; other passphrases are
+/// rejected rather than derived incorrectly.
+///
+/// # Arguments
+/// * `mnemonic` - A valid BIP-39 mnemonic
+/// * `passphrase` - Printable ASCII passphrase (may be empty)
+///
+/// # Returns
+/// * `Ok([u8; 64])` - PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase, 2048)
+/// * `Err(Error::InvalidPassphrase)` - If the passphrase is not printable ASCII
+/// * `Err(Error)` - See [`mnemonic_to_entropy`]
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; SEED_LENGTH], Error> {
+    if !passphrase.bytes().all(|c| (32..=126).contains(&c)) {
+        return Err(Error::InvalidPassphrase);
+    }
+    let entropy = mnemonic_to_entropy(mnemonic)?;
+    let sentence = entropy_to_mnemonic(&entropy)?;
+
+    let salt = format!("{}{}", SALT_PREFIX, passphrase);
+    let mut seed = [0u8; SEED_LENGTH];
+    pbkdf2::pbkdf2_hmac::<Sha512>(
+        sentence.as_bytes(),
+        salt.as_bytes(),
+        SEED_ITERATION_COUNT,
+        &mut seed,
+    );
+    Ok(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordlist_checksum() {
+        let content = std::fs::read("const/bip39_english.txt")
+            .expect("Failed to read const/bip39_english.txt");
+        let hash = format!("{:x}", Sha256::digest(&content));
+        assert_eq!(
+            hash, WORDLIST_SHA256,
+            "BIP-39 wordlist checksum mismatch!\n\
+             If this was intentional, update bip39::WORDLIST_SHA256.\n\
+             Otherwise, restore the file from git: git checkout const/bip39_english.txt"
+        );
+    }
+
+    #[test]
+    fn test_wordlist_is_sorted_with_unique_abbreviations() {
+        let words = wordlist();
+        assert_eq!(words.len(), WORD_COUNT);
+        assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+        let abbreviation = |word: &'static str| &word[..word.len().min(4)];
+        assert!(words
+            .windows(2)
+            .all(|pair| abbreviation(pair[0]) != abbreviation(pair[1])));
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        assert_eq!(encode("abandon").unwrap(), "00000000000");
+        assert_eq!(encode("ZOO").unwrap(), "11111111111");
+        assert_eq!(decode("11111111111").unwrap(), "zoo");
+        assert!(matches!(
+            decode("0000000000"),
+            Err(Error::InvalidBip39BinaryLength(10))
+        ));
+        assert!(matches!(
+            encode("academic"),
+            Err(Error::Bip39WordNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_by_prefix() {
+        assert_eq!(
+            index_to_word(find_by_prefix("aban").unwrap().into()).unwrap(),
+            "abandon"
+        );
+        // "act" is a word and the prefix of "action", "actor", ...
+        assert_eq!(
+            index_to_word(find_by_prefix("act").unwrap().into()).unwrap(),
+            "act"
+        );
+        assert!(matches!(
+            find_by_prefix("ab"),
+            Err(Error::AmbiguousPrefix(_, _, _))
+        ));
+        assert!(matches!(
+            parse_index("2048"),
+            Err(Error::Bip39IndexOutOfRange(2048))
+        ));
+        assert!(matches!(parse_index("x"), Err(Error::InvalidBip39Index(_))));
+    }
+
+    #[test]
+    fn test_checksum_detects_changed_word() {
+        let mnemonic = entropy_to_mnemonic(&[0x7f; 16]).unwrap();
+        assert_eq!(mnemonic_to_entropy(&mnemonic).unwrap(), [0x7f; 16]);
+        let changed = mnemonic.replacen("legal", "winner", 1);
+        assert!(matches!(
+            mnemonic_to_entropy(&changed),
+            Err(Error::InvalidBip39Checksum)
+        ));
+        assert!(matches!(
+            mnemonic_to_entropy("abandon abandon about"),
+            Err(Error::InvalidBip39MnemonicLength(3))
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Passphrase encryption of the master secret
+//!
+//! SLIP-39 encrypts the master secret with a four-round Feistel network whose
+//! round function is PBKDF2-HMAC-SHA256. The iteration exponent scales the
+//! PBKDF2 cost. For non-extendable backups the salt is built from the share set
+//! identifier; extendable backups omit it, so that new share sets with other
+//! identifiers can be issued for the same encrypted master secret.
+
+use crate::shamir::validate_secret_length;
+use crate::Error;
+use sha2::Sha256;
+
+/// Number of Feistel rounds
+pub const ROUND_COUNT: u8 = 4;
+
+/// Total PBKDF2 iterations across all rounds for an iteration exponent of 0
+pub const BASE_ITERATION_COUNT: u32 = 10000;
+
+/// Largest iteration exponent that fits in the 4-bit share field
+pub const MAX_ITERATION_EXPONENT: u8 = 15;
+
+/// Customization string prepended to the identifier in the salt
+const CUSTOMIZATION: &[u8] = b"shamir";
+
+/// A master secret encrypted with a passphrase, together with the parameters
+/// needed to decrypt it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedMasterSecret {
+    /// Random identifier of the share set (15 bits)
+    pub identifier: u16,
+    /// Extendable backup flag (the salt does not depend on the identifier)
+    pub extendable: bool,
+    /// Iteration exponent controlling the PBKDF2 cost (0-15)
+    pub iteration_exponent: u8,
+    /// The encrypted master secret
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedMasterSecret {
+    /// Encrypt a master secret with a passphrase
+    ///
+    /// # Arguments
+    /// * `master_secret` - The secret to encrypt (at least 128 bits, multiple of 16 bits)
+    /// * `passphrase` - Printable ASCII passphrase (may be empty)
+    /// * `identifier` - Share set identifier (15 bits)
+    /// * `extendable` - Extendable backup flag
+    /// * `iteration_exponent` - PBKDF2 cost exponent (0-15)
+    ///
+    /// # Returns
+    /// * `Ok(EncryptedMasterSecret)` - The encrypted secret
+    /// * `Err(Error::InvalidSecretLength)` - If the secret length is not allowed
+    /// * `Err(Error::InvalidPassphrase)` - If the passphrase is not printable ASCII
+    /// * `Err(Error::InvalidParameters)` - If the iteration exponent is above 15
+    pub fn from_master_secret(
+        master_secret: &[u8],
+        passphrase: &[u8],
+        identifier: u16,
+        extendable: bool,
+        iteration_exponent: u8,
+    ) -> Result<Self, Error> {
+        validate_secret_length(master_secret)?;
+        validate_passphrase(passphrase)?;
+        if iteration_exponent > MAX_ITERATION_EXPONENT {
+            return Err(Error::InvalidParameters(format!(
+                "iteration exponent ({}) must not exceed {}",
+                iteration_exponent, MAX_ITERATION_EXPONENT
+            )));
+        }
+
+        let salt = salt(identifier, extendable);
+        let ciphertext = feistel(
+            master_secret,
+            passphrase,
+            iteration_exponent,
+            &salt,
+            0..ROUND_COUNT,
+        );
+        Ok(Self {
+            identifier,
+            extendable,
+            iteration_exponent,
+            ciphertext,
+        })
+    }
+
+    /// Decrypt the master secret with a passphrase
+    ///
+    /// Any passphrase decrypts to some secret: a wrong passphrase cannot be
+    /// detected and simply yields a different master secret.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The master secret
+    /// * `Err(Error::InvalidPassphrase)` - If the passphrase is not printable ASCII
+    pub fn decrypt(&self, passphrase: &[u8]) -> Result<Vec<u8>, Error> {
+        validate_passphrase(passphrase)?;
+        let salt = salt(self.identifier, self.extendable);
+        Ok(feistel(
+            &self.ciphertext,
+            passphrase,
+            self.iteration_exponent,
+            &salt,
+            (0..ROUND_COUNT).rev(),
+        ))
+    }
+}
+
+/// Reject passphrases with characters outside printable ASCII (32-126)
+fn validate_passphrase(passphrase: &[u8]) -> Result<(), Error> {
+    if passphrase.iter().all(|&c| (32..=126).contains(&c)) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPassphrase)
+    }
+}
+
+/// Salt prefix: `"shamir" || identifier` (big-endian), or empty when extendable
+fn salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        return Vec::new();
+    }
+    let mut salt = CUSTOMIZATION.to_vec();
+    salt.extend_from_slice(&identifier.to_be_bytes());
+    salt
+}
+
+/// Run the Feistel network over `input` with the given round order
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+    rounds: impl Iterator<Item = u8>,
+) -> Vec<u8> {
+    let half = input.len() / 2;
+    let mut left = input[..half].to_vec();
+    let mut right = input[half..].to_vec();
+
+    for round in rounds {
+        let f = round_function(round, passphrase, iteration_exponent, salt, &right);
+        let next_right: Vec<u8> = left.iter().zip(&f).map(|(l, f)| l ^ f).collect();
+        left = std::mem::replace(&mut right, next_right);
+    }
+
+    // The halves are swapped after the last round
+    right.extend_from_slice(&left);
+    right
+}
+
+/// F(i, R) = PBKDF2(i || passphrase, salt || R, (10000 << e) / 4)
+fn round_function(
+    round: u8,
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+    right: &[u8],
+) -> Vec<u8> {
+    let mut password = vec![round];
+    password.extend_from_slice(passphrase);
+
+    let mut round_salt = salt.to_vec();
+    round_salt.extend_from_slice(right);
+
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / u32::from(ROUND_COUNT);
+    let mut output = vec![0u8; right.len()];
+    pbkdf2::pbkdf2_hmac::<Sha256>(&password, &round_salt, iterations, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 16] = *b"ABCDEFGHIJKLMNOP";
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let ems =
+            EncryptedMasterSecret::from_master_secret(&SECRET, b"TREZOR", 42, false, 0).unwrap();
+        assert_ne!(ems.ciphertext, SECRET);
+        assert_eq!(ems.decrypt(b"TREZOR").unwrap(), SECRET);
+        assert_ne!(ems.decrypt(b"trezor").unwrap(), SECRET);
+    }
+
+    #[test]
+    fn test_identifier_changes_ciphertext() {
+        let a = EncryptedMasterSecret::from_master_secret(&SECRET, b"", 1, false, 0).unwrap();
+        let b = EncryptedMasterSecret::from_master_secret(&SECRET, b"", 2, false, 0).unwrap();
+        assert_ne!(a.ciphertext, b.ciphertext);
+    }
+
+    #[test]
+    fn test_extendable_ignores_identifier() {
+        let a = EncryptedMasterSecret::from_master_secret(&SECRET, b"", 1, true, 0).unwrap();
+        let b = EncryptedMasterSecret::from_master_secret(&SECRET, b"", 2, true, 0).unwrap();
+        assert_eq!(a.ciphertext, b.ciphertext);
+        assert_eq!(b.decrypt(b"").unwrap(), SECRET);
+    }
+
+    #[test]
+    fn test_rejects_non_printable_passphrase() {
+        assert!(matches!(
+            EncryptedMasterSecret::from_master_secret(&SECRET, "pässword".as_bytes(), 1, false, 0),
+            Err(Error::InvalidPassphrase)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_large_iteration_exponent() {
+        assert!(matches!(
+            EncryptedMasterSecret::from_master_secret(&SECRET, b"", 1, false, 16),
+            Err(Error::InvalidParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+//! Arithmetic in GF(256) as used by SLIP-39 Shamir sharing
+//!
+//! The field is GF(2^8) with the Rijndael reduction polynomial
+//! x^8 + x^4 + x^3 + x + 1. Addition is XOR.
+//!
+//! Multiplication, inversion and interpolation are implemented without
+//! secret-dependent branches or table lookups, so the share values and the
+//! secret do not leak through timing. The [`EXP`] / [`LOG`] tables are provided
+//! for inspecting the field and must only be indexed with public values.
+
+use crate::Error;
+
+/// Low byte of the reduction polynomial x^8 + x^4 + x^3 + x + 1
+const REDUCTION: u8 = 0x1B;
+
+/// Powers of the generator 3: `EXP[i] = 3^i` for `i` in 0..255
+pub const EXP: [u8; 255] = build_exp();
+
+/// Discrete logarithms base 3: `LOG[EXP[i]] = i` (`LOG[0]` is unused)
+pub const LOG: [u8; 256] = build_log();
+
+const fn build_exp() -> [u8; 255] {
+    let mut table = [0u8; 255];
+    let mut value: u8 = 1;
+    let mut i = 0;
+    while i < 255 {
+        table[i] = value;
+        // value *= 3, i.e. value * x + value
+        let carry = if value & 0x80 != 0 { REDUCTION } else { 0 };
+        value ^= (value << 1) ^ carry;
+        i += 1;
+    }
+    table
+}
+
+const fn build_log() -> [u8; 256] {
+    let exp = build_exp();
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[exp[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Add (or subtract) two field elements
+pub fn add(a: u8, b: u8) -> u8 {
+    a ^ b
+}
+
+/// Multiply two field elements in constant time
+pub fn mul(a: u8, b: u8) -> u8 {
+    let mut a = a;
+    let mut b = b;
+    let mut product = 0u8;
+    for _ in 0..8 {
+        // Masks are all ones or all zeros, selected without branching
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (REDUCTION & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Multiplicative inverse in constant time (`inv(0)` is defined as 0)
+///
+/// Computed as `a^254` with a fixed square-and-multiply chain.
+pub fn inv(a: u8) -> u8 {
+    let a2 = mul(a, a);
+    let a4 = mul(a2, a2);
+    let a8 = mul(a4, a4);
+    let a16 = mul(a8, a8);
+    let a32 = mul(a16, a16);
+    let a64 = mul(a32, a32);
+    let a128 = mul(a64, a64);
+    // 254 = 128 + 64 + 32 + 16 + 8 + 4 + 2
+    mul(mul(mul(a128, a64), mul(a32, a16)), mul(mul(a8, a4), a2))
+}
+
+/// Divide `a` by `b` in constant time (`b` must be non-zero)
+pub fn div(a: u8, b: u8) -> u8 {
+    mul(a, inv(b))
+}
+
+/// Evaluate at `x` the polynomial of lowest degree passing through the given points
+///
+/// Each point is an `(x, y)` pair where `y` is a byte string; the interpolation
+/// is done independently for every byte position.
+///
+/// # Arguments
+/// * `points` - Points with distinct x-coordinates and equal-length values
+/// * `x` - The x-coordinate to evaluate at
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The interpolated value at `x`
+/// * `Err(Error::InsufficientShares)` - If no points are given
+/// * `Err(Error::InconsistentShares)` - If x-coordinates repeat or value lengths differ
+pub fn interpolate(points: &[(u8, &[u8])], x: u8) -> Result<Vec<u8>, Error> {
+    let Some(&(_, first)) = points.first() else {
+        return Err(Error::InsufficientShares(
+            "at least one share is required for interpolation".to_string(),
+        ));
+    };
+
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        if points[..i].iter().any(|&(xj, _)| xj == xi) {
+            return Err(Error::InconsistentShares(
+                "share indices must be unique".to_string(),
+            ));
+        }
+        if yi.len() != first.len() {
+            return Err(Error::InconsistentShares(
+                "all share values must have the same length".to_string(),
+            ));
+        }
+    }
+
+    // The x-coordinates are public, but the same constant-time primitives
+    // are used throughout so that only the point count affects timing.
+    let mut result = vec![0u8; first.len()];
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut numerator = 1u8;
+        let mut denominator = 1u8;
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i != j {
+                numerator = mul(numerator, add(x, xj));
+                denominator = mul(denominator, add(xi, xj));
+            }
+        }
+        let basis = div(numerator, denominator);
+        for (r, &y) in result.iter_mut().zip(yi) {
+            *r ^= mul(basis, y);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table_mul(a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            EXP[(usize::from(LOG[a as usize]) + usize::from(LOG[b as usize])) % 255]
+        }
+    }
+
+    #[test]
+    fn test_tables_are_consistent() {
+        assert_eq!(EXP[0], 1);
+        assert_eq!(EXP[1], 3);
+        for i in 0..255 {
+            assert_eq!(usize::from(LOG[EXP[i] as usize]), i);
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_tables() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!(mul(a, b), table_mul(a, b), "{} * {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(inv(0), 0);
+        for a in 1..=255u8 {
+            assert_eq!(mul(a, inv(a)), 1, "inverse of {}", a);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_line() {
+        // f(x) = 5 + 7x for every byte
+        let f = |x: u8| vec![add(5, mul(7, x)); 3];
+        let (y1, y2) = (f(1), f(2));
+        let points = [(1, y1.as_slice()), (2, y2.as_slice())];
+
+        assert_eq!(interpolate(&points, 0).unwrap(), f(0));
+        assert_eq!(interpolate(&points, 200).unwrap(), f(200));
+        assert_eq!(interpolate(&points, 2).unwrap(), y2);
+    }
+
+    #[test]
+    fn test_interpolate_rejects_duplicates() {
+        let y = [1u8, 2];
+        let result = interpolate(&[(1, &y[..]), (1, &y[..])], 0);
+        assert!(matches!(result, Err(Error::InconsistentShares(_))));
+    }
+
+    #[test]
+    fn test_interpolate_rejects_length_mismatch() {
+        let result = interpolate(&[(1, &[1u8, 2][..]), (2, &[1u8][..])], 0);
+        assert!(matches!(result, Err(Error::InconsistentShares(_))));
+    }
+}
@@ -2,15 +2,54 @@
 //!
 //! This library provides functions to encode and decode SLIP-39 mnemonic words
 //! to/from their 10-bit binary representation.
+//!
+//! The [`bip39`] module does the same for the 11-bit BIP-39 wordlist, and
+//! validates BIP-39 mnemonics and derives their seeds.
+
+pub mod bip32;
+pub mod bip39;
+pub mod cipher;
+pub mod gf256;
+pub mod packing;
+pub mod parser;
+pub mod repair;
+pub mod rs1024;
+pub mod shamir;
+pub mod share;
+pub mod status;
+pub mod suggest;
+pub mod word_index;
+
+pub use bip32::{DerivationPath, ExtendedPrivateKey};
+pub use cipher::EncryptedMasterSecret;
+pub use packing::{bytes_to_indices, indices_to_bytes};
+pub use parser::{parse_mnemonic, parse_mnemonics, ParseError, ParsedMnemonic};
+pub use repair::{
+    fill_erasures, fill_mnemonic_erasures, repair_indices, repair_mnemonic, Correction,
+    CorrectionKind,
+};
+pub use rs1024::{create_checksum, verify_checksum, Customization};
+pub use shamir::{
+    combine, combine_mnemonics, recover_ems, reshare, split, split_ems, split_groups,
+};
+pub use share::Share;
+pub use status::{share_status, GroupStatus, SetStatus};
+pub use suggest::{suggest, Suggestion};
+pub use word_index::WordIndex;
 
 use rand::Rng;
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
 /// Errors that can occur during encoding/decoding
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Word '{0}' not found in SLIP-39 wordlist")]
-    WordNotFound(String),
+    #[error("Word '{word}' not found in SLIP-39 wordlist{}", did_you_mean(.suggestions))]
+    WordNotFound {
+        word: String,
+        /// Closest words of the wordlist, best first (see [`suggest`])
+        suggestions: Vec<&'static str>,
+    },
 
     #[error("Invalid binary string: {0}")]
     InvalidBinary(String),
@@ -23,6 +62,101 @@ pub enum Error {
 
     #[error("Index {0} out of wordlist range (0-1023)")]
     IndexOutOfRange(usize),
+
+    #[error("Invalid word index '{0}': expected a number from 0 to 1023")]
+    InvalidIndex(String),
+
+    #[error("Invalid mnemonic length: {0} words cannot encode a valid share")]
+    InvalidMnemonicLength(usize),
+
+    #[error("Invalid mnemonic padding: padding bits of the share value must be zero")]
+    InvalidPadding,
+
+    #[error("Invalid mnemonic checksum")]
+    InvalidChecksum,
+
+    #[error("Invalid share: {0}")]
+    InvalidShare(String),
+
+    #[error("Inconsistent shares: {0}")]
+    InconsistentShares(String),
+
+    #[error("Insufficient shares: {0}")]
+    InsufficientShares(String),
+
+    #[error("Invalid digest of the shared secret")]
+    InvalidDigest,
+
+    #[error("Passphrase must only contain printable ASCII characters")]
+    InvalidPassphrase,
+
+    #[error("Invalid parameters: {0}")]
+    InvalidParameters(String),
+
+    #[error("Master secret must be at least 128 bits and a multiple of 16 bits, got {0} bits")]
+    InvalidSecretLength(usize),
+
+    #[error("Invalid hex string: {0}")]
+    InvalidHex(String),
+
+    #[error("Word '{0}' not found in BIP-39 wordlist")]
+    Bip39WordNotFound(String),
+
+    #[error("Index {0} out of BIP-39 wordlist range (0-2047)")]
+    Bip39IndexOutOfRange(usize),
+
+    #[error("Invalid BIP-39 word index '{0}': expected a number from 0 to 2047")]
+    InvalidBip39Index(String),
+
+    #[error("BIP-39 binary must be exactly 11 bits, got {0} bits")]
+    InvalidBip39BinaryLength(usize),
+
+    #[error("Invalid BIP-39 mnemonic length: {0} words (expected 12, 15, 18, 21 or 24)")]
+    InvalidBip39MnemonicLength(usize),
+
+    #[error("Invalid BIP-39 mnemonic checksum")]
+    InvalidBip39Checksum,
+
+    #[error("BIP-39 entropy must be 128 to 256 bits in steps of 32 bits, got {0} bits")]
+    InvalidEntropyLength(usize),
+
+    #[error("Invalid derivation path '{0}': expected e.g. m/84'/0'/0'")]
+    InvalidDerivationPath(String),
+
+    #[error("Derived key is invalid ({0}); this has a probability below 2^-127")]
+    InvalidDerivedKey(String),
+
+    #[error("Fingerprint mismatch: expected {0}, the recovered wallet has {1}")]
+    FingerprintMismatch(String, String),
+
+    #[error("Line {line}, column {column}: {source}")]
+    InvalidInput {
+        line: usize,
+        column: usize,
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// An [`Error::WordNotFound`] for `word`, with the closest words of the wordlist
+    pub fn word_not_found(word: &str) -> Self {
+        Error::WordNotFound {
+            word: word.to_string(),
+            suggestions: suggest(word, suggest::ERROR_SUGGESTIONS)
+                .iter()
+                .map(|s| s.word)
+                .collect(),
+        }
+    }
+}
+
+/// " (did you mean: a, b?)" for a non-empty list of suggestions
+fn did_you_mean(suggestions: &[&str]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" (did you mean: {}?)", suggestions.join(", "))
+    }
 }
 
 /// The complete SLIP-39 wordlist (1024 words)
@@ -43,6 +177,20 @@ pub fn wordlist() -> &'static [&'static str] {
     })
 }
 
+/// Position of every word in the wordlist, built on first access
+static WORD_INDICES: OnceLock<HashMap<&'static str, u16>> = OnceLock::new();
+
+/// Get the word -> index map, initializing it if necessary
+pub(crate) fn word_indices() -> &'static HashMap<&'static str, u16> {
+    WORD_INDICES.get_or_init(|| {
+        wordlist()
+            .iter()
+            .enumerate()
+            .map(|(index, &word)| (word, index as u16))
+            .collect()
+    })
+}
+
 /// Get a word from the wordlist by its index (0-1023)
 ///
 /// # Arguments
@@ -52,10 +200,7 @@ pub fn wordlist() -> &'static [&'static str] {
 /// * `Ok(&str)` - The word at the given index
 /// * `Err(Error::IndexOutOfRange)` - If index is > 1023
 pub fn get_word_by_index(index: usize) -> Result<&'static str, Error> {
-    wordlist()
-        .get(index)
-        .copied()
-        .ok_or(Error::IndexOutOfRange(index))
+    WordIndex::try_from(index).map(WordIndex::word)
 }
 
 /// Get a random word from the wordlist using os-seeded CSPRNG.
@@ -83,17 +228,18 @@ pub fn get_random_word() -> &'static str {
 pub fn find_matches(prefix: &str) -> Vec<&'static str> {
     let normalized = prefix.trim().to_lowercase();
 
-    // Optimizaton: check for exact match first
-    for &word in wordlist() {
-        if word == normalized {
-            return vec![word];
-        }
+    // An exact match wins even if it is also the prefix of other words
+    if let Some(&index) = word_indices().get(normalized.as_str()) {
+        return vec![wordlist()[usize::from(index)]];
     }
 
-    wordlist()
+    // The wordlist is sorted, so the matches form a contiguous range
+    let words = wordlist();
+    let start = words.partition_point(|&word| word < normalized.as_str());
+    words[start..]
         .iter()
-        .filter(|&&word| word.starts_with(&normalized))
-        .cloned()
+        .take_while(|word| word.starts_with(&normalized))
+        .copied()
         .collect()
 }
 
@@ -110,7 +256,7 @@ pub fn find_by_prefix(prefix: &str) -> Result<String, Error> {
     let matches = find_matches(prefix);
 
     match matches.len() {
-        0 => Err(Error::WordNotFound(prefix.to_string())),
+        0 => Err(Error::word_not_found(prefix)),
         1 => Ok(matches[0].to_string()),
         count => {
             // Show first 3 matches in error message
@@ -130,6 +276,55 @@ pub fn find_by_prefix(prefix: &str) -> Result<String, Error> {
     }
 }
 
+/// Convert a whitespace-separated mnemonic to its word indices
+///
+/// Words are looked up with [`WordIndex::from_word_ct`], so the time taken does
+/// not depend on which words the mnemonic contains.
+///
+/// # Arguments
+/// * `mnemonic` - SLIP-39 words separated by whitespace (case-insensitive)
+///
+/// # Returns
+/// * `Ok(Vec<u16>)` - The index (0-1023) of every word, in order
+/// * `Err(Error::WordNotFound)` - If any word is not in the wordlist
+pub fn mnemonic_to_indices(mnemonic: &str) -> Result<Vec<u16>, Error> {
+    mnemonic
+        .split_whitespace()
+        .map(|word| WordIndex::from_word_ct(word).map(WordIndex::value))
+        .collect()
+}
+
+/// Verify the RS1024 checksum of a complete SLIP-39 mnemonic
+///
+/// The customization string ("shamir" or "shamir_extendable") is selected from
+/// the extendable backup flag, which is bit 15 of the share header.
+///
+/// # Arguments
+/// * `mnemonic` - The full mnemonic, including the three checksum words
+///
+/// # Returns
+/// * `Ok(true)` - If the last three words are a valid checksum
+/// * `Ok(false)` - If the checksum does not match
+/// * `Err(Error::WordNotFound)` - If any word is not in the wordlist
+///
+/// # Example
+/// ```
+/// use slip39_calculator::verify_mnemonic_checksum;
+///
+/// let share = "duckling enlarge academic academic agency result length solution fridge \
+///              kidney coal piece deal husband erode duke ajar critical decision keyboard";
+/// assert!(verify_mnemonic_checksum(share).unwrap());
+/// ```
+pub fn verify_mnemonic_checksum(mnemonic: &str) -> Result<bool, Error> {
+    let indices = mnemonic_to_indices(mnemonic)?;
+    // The extendable flag is the 6th bit of the second word
+    let extendable = indices.get(1).is_some_and(|&w| (w >> 4) & 1 == 1);
+    Ok(verify_checksum(
+        &indices,
+        Customization::from_extendable(extendable),
+    ))
+}
+
 /// SHA256 checksum of the official wordlist.txt file
 /// This ensures the wordlist matches the official SLIP-39 specification.
 /// Official commit: 1524583213f1392321109b0ff0a91330836ecb32 (2019-03-02)
@@ -156,12 +351,7 @@ pub const WORDLIST_SHA256: &str =
 /// assert_eq!(binary, "0000000001");
 /// ```
 pub fn encode(word: &str) -> Result<String, Error> {
-    let normalized = word.trim().to_lowercase();
-    wordlist()
-        .iter()
-        .position(|&w| w == normalized)
-        .map(|index| format!("{:010b}", index))
-        .ok_or_else(|| Error::WordNotFound(word.to_string()))
+    WordIndex::from_word(word).map(WordIndex::to_bits)
 }
 
 /// Decode a 10-bit binary string to its SLIP-39 word
@@ -181,29 +371,7 @@ pub fn encode(word: &str) -> Result<String, Error> {
 /// assert_eq!(word, "acid");
 /// ```
 pub fn decode(binary: &str) -> Result<String, Error> {
-    // Validate length
-    if binary.len() != 10 {
-        return Err(Error::InvalidBinaryLength(binary.len()));
-    }
-
-    // Validate characters
-    if !binary.chars().all(|c| c == '0' || c == '1') {
-        return Err(Error::InvalidBinary(
-            "Binary string must only contain '0' and '1'".to_string(),
-        ));
-    }
-
-    // Parse binary to index
-    let index =
-        usize::from_str_radix(binary, 2).map_err(|e| Error::InvalidBinary(e.to_string()))?;
-
-    // Get word from wordlist
-    wordlist()
-        .get(index)
-        .map(|&w| w.to_string())
-        .ok_or_else(|| {
-            Error::InvalidBinary(format!("Index {} out of wordlist range (0-1023)", index))
-        })
+    WordIndex::from_bits(binary).map(|index| index.word().to_string())
 }
 
 #[cfg(test)]
@@ -229,7 +397,7 @@ mod tests {
     fn test_encode_word_not_found() {
         let result = encode("notaword");
         assert!(result.is_err());
-        assert!(matches!(result, Err(Error::WordNotFound(_))));
+        assert!(matches!(result, Err(Error::WordNotFound { .. })));
     }
 
     #[test]
@@ -268,6 +436,24 @@ mod tests {
         assert!(matches!(result, Err(Error::InvalidBinary(_))));
     }
 
+    #[test]
+    fn test_wordlist_is_sorted() {
+        // find_matches relies on the order to find prefix ranges
+        assert!(wordlist().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find_matches_prefix_range() {
+        assert_eq!(find_matches("acad"), vec!["academic"]);
+        assert_eq!(
+            find_matches("ac"),
+            vec!["academic", "acid", "acne", "acquire", "acrobat", "activity", "actress"]
+        );
+        assert_eq!(find_matches("ZERO"), vec!["zero"]);
+        assert!(find_matches("zz").is_empty());
+        assert_eq!(find_matches("").len(), 1024);
+    }
+
     #[test]
     fn test_roundtrip() {
         let word = "acquire";
@@ -1,8 +1,14 @@
 mod tui;
 
-use clap::{Parser, Subcommand, ValueEnum};
-use slip39_calculator::{decode, encode, get_random_word, wordlist};
-use std::process;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use slip39_calculator::bip32::{verify_fingerprint, FINGERPRINT_LENGTH};
+use slip39_calculator::{
+    bip39, combine, decode, fill_mnemonic_erasures, get_random_word, parse_mnemonics,
+    parser::tokenize, repair::ERASURE_MARKER, repair_mnemonic, reshare, share::layout,
+    share_status, split_groups, verify_checksum, wordlist, CorrectionKind, Customization,
+    DerivationPath, ExtendedPrivateKey, Share, WordIndex,
+};
+use std::{fs, io, path::PathBuf, process};
 
 /// SLIP-39 wordlist encoder/decoder
 ///
@@ -15,7 +21,7 @@ use std::process;
 #[command(about = "SLIP-39 Wordlist Calculator")]
 #[command(
     long_about = "SLIP-39 Wordlist Calculator\n\nBy default, this tool launches an interactive TUI (Terminal User Interface) for exploring the wordlist and calculating 10-bit binary representations.\n\nRun 'slip39c --help' for CLI commands.",
-    after_long_help = "Note: Stateless mode encodes data using the SLIP-39 format, but generated phrases are independent and cannot be combined for recovery."
+    after_long_help = "Note: Phrases built word by word in the TUI or with generate are independent and are not part of a recovery scheme. Use 'split' to create shares that 'combine' can recover, or 'from-bip39' and 'to-bip39' for an existing BIP-39 wallet."
 )]
 struct Cli {
     #[command(subcommand)]
@@ -36,51 +42,76 @@ enum Commands {
         mode: Option<InputModeArg>,
     },
 
-    /// Encode a SLIP-39 word to its 10-bit binary representation
+    /// Encode a word to its binary representation (10 bits for SLIP-39, 11 for BIP-39)
     #[command(name = "encode-word")]
     EncodeWord {
-        /// The SLIP-39 word to encode
+        /// The word to encode
         word: String,
 
-        /// Allow fuzzy matching by unique prefix
+        /// Accept four-letter abbreviations and other unique prefixes
         #[arg(long, short)]
         prefix: bool,
+
+        /// Wordlist of the word (SLIP-39 or BIP-39)
+        #[arg(long, value_enum, default_value_t = Scheme::Slip39)]
+        scheme: Scheme,
     },
 
-    /// Decode a 10-bit binary string to its SLIP-39 word
+    /// Decode a binary string (10 bits for SLIP-39, 11 for BIP-39) to its word
     #[command(name = "decode-bits")]
     DecodeBits {
-        /// The 10-bit binary string to decode (e.g., "0000000001")
+        /// The binary string to decode (e.g., "0000000001")
         binary: String,
+
+        /// Wordlist of the result (SLIP-39 or BIP-39)
+        #[arg(long, value_enum, default_value_t = Scheme::Slip39)]
+        scheme: Scheme,
     },
 
-    /// Get the index (0-1023) of a SLIP-39 word
+    /// Get the index of a word (0-1023 for SLIP-39, 0-2047 for BIP-39)
     #[command(name = "word-to-index")]
     WordToIndex {
-        /// The SLIP-39 word to look up
+        /// The word to look up
         word: String,
 
-        /// Allow fuzzy matching by unique prefix
+        /// Accept four-letter abbreviations and other unique prefixes
         #[arg(long, short)]
         prefix: bool,
+
+        /// Wordlist of the word (SLIP-39 or BIP-39)
+        #[arg(long, value_enum, default_value_t = Scheme::Slip39)]
+        scheme: Scheme,
     },
 
-    /// Get the SLIP-39 word at a specific index (0-1023)
+    /// Get the word at a specific index (0-1023 for SLIP-39, 0-2047 for BIP-39)
     #[command(name = "index-to-word")]
     IndexToWord {
-        /// The index (0-1023) of the word to retrieve
-        index: usize,
+        /// The index of the word to retrieve
+        index: String,
+
+        /// Wordlist of the result (SLIP-39 or BIP-39)
+        #[arg(long, value_enum, default_value_t = Scheme::Slip39)]
+        scheme: Scheme,
     },
 
-    /// Explain a word: show word -> index -> bits
+    /// Explain a word (word -> index -> bits) or every field of a full mnemonic
+    ///
+    /// Given a full share mnemonic, prints each word with its bits, then every
+    /// field with its value and bit span, and the checksum status. With
+    /// --scheme bip39, a full mnemonic is broken down into entropy and checksum.
     #[command(name = "explain")]
     Explain {
-        /// The SLIP-39 word to explain
-        word: String,
+        /// The word to explain, or a full mnemonic (quoted, or as separate words)
+        #[arg(required = true, num_args = 1..)]
+        word: Vec<String>,
 
-        /// Allow fuzzy matching by unique prefix
+        /// Accept four-letter abbreviations and other unique prefixes
         #[arg(long, short)]
         prefix: bool,
+
+        /// Wordlist of the words (SLIP-39 or BIP-39)
+        #[arg(long, value_enum, default_value_t = Scheme::Slip39)]
+        scheme: Scheme,
     },
 
     /// Generate random SLIP-39 words using a CSPRNG
@@ -90,6 +121,239 @@ enum Commands {
         #[arg(long, short, default_value = "20")]
         count: usize,
     },
+
+    /// Split a master secret into a set of SLIP-39 shares
+    ///
+    /// Use --threshold/--count for a single group, or --group-threshold with one
+    /// --group per group for the two-level scheme. Groups are separated by a blank line.
+    #[command(name = "split")]
+    Split {
+        /// The master secret as a hex string (128 or 256 bits)
+        secret: String,
+
+        #[command(flatten)]
+        options: SplitOptions,
+    },
+
+    /// Back up a BIP-39 mnemonic as a set of SLIP-39 shares of its entropy
+    ///
+    /// The BIP-39 checksum is verified and the entropy is split like a master
+    /// secret. Recover the phrase with to-bip39: a SLIP-39 wallet would derive
+    /// a different wallet from the same shares.
+    #[command(name = "from-bip39")]
+    FromBip39 {
+        /// The BIP-39 mnemonic (quoted, or as separate words)
+        #[arg(required = true, num_args = 1..)]
+        mnemonic: Vec<String>,
+
+        #[command(flatten)]
+        options: SplitOptions,
+    },
+
+    /// Combine shares created by from-bip39 and print the BIP-39 mnemonic
+    ///
+    /// Mnemonics are taken from the arguments, from --file, or from stdin, in
+    /// the same format as combine.
+    #[command(name = "to-bip39")]
+    ToBip39 {
+        /// Share mnemonics (quote each mnemonic)
+        mnemonics: Vec<String>,
+
+        /// Read mnemonics from a file
+        #[arg(long, short, conflicts_with = "mnemonics")]
+        file: Option<PathBuf>,
+
+        /// Passphrase used when the shares were created
+        #[arg(long, short, default_value = "")]
+        passphrase: String,
+    },
+
+    /// Combine shares to recover the master secret
+    ///
+    /// Mnemonics are taken from the arguments, from --file, or from stdin.
+    /// Shares are separated by blank lines or given one per line; numbering,
+    /// commas, mixed case and four-letter abbreviations are accepted.
+    /// The master secret is the BIP-32 seed of the wallet: --fingerprint and
+    /// --xpub identify the wallet it restores.
+    #[command(name = "combine")]
+    Combine {
+        /// Share mnemonics (quote each mnemonic)
+        mnemonics: Vec<String>,
+
+        /// Read mnemonics from a file
+        #[arg(long, short, conflicts_with = "mnemonics")]
+        file: Option<PathBuf>,
+
+        /// Passphrase used when the shares were created
+        #[arg(long, short, default_value = "")]
+        passphrase: String,
+
+        /// Also print the BIP-32 master key fingerprint
+        #[arg(long)]
+        fingerprint: bool,
+
+        /// Also print the xpub at this derivation path (e.g. m/84'/0'/0')
+        #[arg(long, value_name = "PATH")]
+        xpub: Option<DerivationPath>,
+    },
+
+    /// Check that shares and passphrase restore the wallet with a known fingerprint
+    ///
+    /// Combines the shares, derives the BIP-32 master key fingerprint and reports
+    /// whether it matches; the master secret is never printed. A wrong passphrase
+    /// restores a different wallet, which shows up here as a mismatch (exit code 1).
+    /// Mnemonics are taken from the arguments, from --file, or from stdin, in the
+    /// same format as combine.
+    #[command(name = "verify")]
+    Verify {
+        /// Share mnemonics (quote each mnemonic)
+        mnemonics: Vec<String>,
+
+        /// Read mnemonics from a file
+        #[arg(long, short, conflicts_with = "mnemonics")]
+        file: Option<PathBuf>,
+
+        /// Passphrase used when the shares were created
+        #[arg(long, short, default_value = "")]
+        passphrase: String,
+
+        /// Expected master key fingerprint (8 hex digits, as shown by the wallet)
+        #[arg(long, value_parser = parse_fingerprint)]
+        fingerprint: [u8; FINGERPRINT_LENGTH],
+
+        /// The shares were created by from-bip39: derive the wallet from the BIP-39 phrase
+        #[arg(long)]
+        bip39: bool,
+
+        /// BIP-39 passphrase of the wallet (with --bip39)
+        #[arg(long, default_value = "", requires = "bip39")]
+        bip39_passphrase: String,
+    },
+
+    /// Issue a new share set with another group layout from enough shares of a backup
+    ///
+    /// The encrypted master secret is recovered and split again without being
+    /// decrypted, so no passphrase is needed and the wallet stays the same.
+    /// Extendable backups get a new identifier. Non-extendable ones can only keep
+    /// theirs, so they are refused unless --reuse-identifier is given: their old
+    /// and new shares then look like one set and must never be combined together.
+    /// The old shares still recover the secret: destroy them once the new set is stored.
+    /// Mnemonics are taken from the arguments, from --file, or from stdin, in the
+    /// same format as combine.
+    #[command(name = "reshare")]
+    Reshare {
+        /// Share mnemonics (quote each mnemonic)
+        mnemonics: Vec<String>,
+
+        /// Read mnemonics from a file
+        #[arg(long, short, conflicts_with = "mnemonics")]
+        file: Option<PathBuf>,
+
+        #[command(flatten)]
+        layout: ShareLayout,
+
+        /// Reshare a non-extendable backup under its current identifier
+        #[arg(long)]
+        reuse_identifier: bool,
+
+        /// Print four-letter abbreviations instead of full words
+        #[arg(long, short)]
+        abbreviate: bool,
+    },
+
+    /// Suggest single-word corrections for a share with an invalid checksum
+    ///
+    /// Tries every substitution at every position; an unknown word is taken as
+    /// the wrong one. Mark up to three missing or illegible words with '?' to
+    /// fill them in instead. Prints each candidate followed by the corrected mnemonic.
+    #[command(name = "repair")]
+    Repair {
+        /// The share mnemonic (quoted, or as separate words)
+        #[arg(required = true, num_args = 1..)]
+        mnemonic: Vec<String>,
+
+        /// Also try swapping adjacent words
+        #[arg(long, short)]
+        transpositions: bool,
+    },
+
+    /// Report the recovery progress of a collection of shares
+    ///
+    /// Lists the share sets present and, for every group, how many more members
+    /// are needed. The secret is never reconstructed. Mnemonics are taken from
+    /// the arguments, from --file, or from stdin, in the same format as combine.
+    #[command(name = "status")]
+    Status {
+        /// Share mnemonics (quote each mnemonic)
+        mnemonics: Vec<String>,
+
+        /// Read mnemonics from a file
+        #[arg(long, short, conflicts_with = "mnemonics")]
+        file: Option<PathBuf>,
+    },
+}
+
+/// Group layout of a new share set
+#[derive(Args)]
+struct ShareLayout {
+    /// Number of shares required to recover the secret (single group)
+    #[arg(
+        long,
+        short,
+        required_unless_present = "group",
+        conflicts_with = "group"
+    )]
+    threshold: Option<u8>,
+
+    /// Total number of shares to create (single group)
+    #[arg(
+        long,
+        short = 'n',
+        required_unless_present = "group",
+        conflicts_with = "group"
+    )]
+    count: Option<u8>,
+
+    /// Number of groups required to recover the secret
+    #[arg(long, short, default_value = "1", requires = "group")]
+    group_threshold: u8,
+
+    /// Member policy of a group as THRESHOLDofCOUNT (e.g. 2of3), repeat per group
+    #[arg(long, value_parser = parse_group)]
+    group: Vec<(u8, u8)>,
+}
+
+impl ShareLayout {
+    /// `(member_threshold, member_count)` of every group
+    fn groups(&self) -> Vec<(u8, u8)> {
+        match (self.threshold, self.count) {
+            (Some(threshold), Some(count)) => vec![(threshold, count)],
+            _ => self.group.clone(),
+        }
+    }
+}
+
+/// Share set policy and encryption options of split and from-bip39
+#[derive(Args)]
+struct SplitOptions {
+    #[command(flatten)]
+    layout: ShareLayout,
+
+    /// Passphrase used to encrypt the master secret
+    #[arg(long, short, default_value = "")]
+    passphrase: String,
+
+    /// PBKDF2 cost exponent of the encryption (0-15)
+    #[arg(long, short, default_value = "1")]
+    iteration_exponent: u8,
+
+    /// Create a non-extendable backup (no further share sets can be issued)
+    #[arg(long)]
+    no_extendable: bool,
+
+    /// Print four-letter abbreviations instead of full words
+    #[arg(long, short)]
+    abbreviate: bool,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
@@ -98,17 +362,484 @@ enum InputModeArg {
     Binary,
 }
 
-// Helper to find input word, optionally using prefix matching (placeholder logic for now)
-fn find_word(word: &str, _use_prefix: bool) -> Result<String, slip39_calculator::Error> {
-    // For now, no fuzzy search in core lib, so just normalize
-    let normalized = word.trim().to_lowercase();
-    if wordlist().contains(&normalized.as_str()) {
-        Ok(normalized)
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Scheme {
+    Slip39,
+    Bip39,
+}
+
+// Helper to find input word, optionally accepting abbreviations and unique prefixes
+fn find_word(word: &str, use_prefix: bool) -> Result<WordIndex, slip39_calculator::Error> {
+    if use_prefix {
+        WordIndex::from_prefix_ct(word)
     } else {
-        Err(slip39_calculator::Error::WordNotFound(word.to_string()))
+        WordIndex::from_word_ct(word)
     }
 }
 
+// Helper to find a BIP-39 word index, optionally accepting abbreviations and unique prefixes
+fn find_bip39_word(word: &str, use_prefix: bool) -> Result<u16, slip39_calculator::Error> {
+    if use_prefix {
+        bip39::find_by_prefix(word)
+    } else {
+        bip39::word_to_index(word)
+    }
+}
+
+// Helper to decode a hex-encoded secret
+fn parse_hex(input: &str) -> Result<Vec<u8>, slip39_calculator::Error> {
+    hex::decode(input.trim()).map_err(|e| slip39_calculator::Error::InvalidHex(e.to_string()))
+}
+
+// Helper to parse a group policy such as "2of3" into (threshold, count)
+fn parse_group(input: &str) -> Result<(u8, u8), String> {
+    let (threshold, count) = input
+        .to_lowercase()
+        .split_once("of")
+        .map(|(t, n)| (t.trim().to_string(), n.trim().to_string()))
+        .ok_or_else(|| format!("expected THRESHOLDofCOUNT (e.g. 2of3), got '{}'", input))?;
+    let threshold = threshold.parse::<u8>().map_err(|e| e.to_string())?;
+    let count = count.parse::<u8>().map_err(|e| e.to_string())?;
+    Ok((threshold, count))
+}
+
+// Helper to parse a master key fingerprint such as "3442193e"
+fn parse_fingerprint(input: &str) -> Result<[u8; FINGERPRINT_LENGTH], String> {
+    let input = input.trim();
+    let hex_digits = input.strip_prefix("0x").unwrap_or(input);
+    hex::decode(hex_digits)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("expected 8 hex digits (e.g. 3442193e), got '{}'", input))
+}
+
+// Helper to collect mnemonic text from arguments (one share each), a file or stdin
+fn read_mnemonics(args: Vec<String>, file: Option<PathBuf>) -> String {
+    if !args.is_empty() {
+        return args.join("\n\n");
+    }
+
+    let content = match file {
+        Some(path) => fs::read_to_string(&path),
+        None => io::read_to_string(io::stdin()),
+    };
+
+    match content {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Error: failed to read mnemonics: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// Helper to split a secret and print the shares, groups separated by a blank line
+fn split_output(secret: &[u8], options: &SplitOptions) -> Result<String, slip39_calculator::Error> {
+    let groups = split_groups(
+        secret,
+        options.passphrase.as_bytes(),
+        !options.no_extendable,
+        options.iteration_exponent,
+        options.layout.group_threshold,
+        &options.layout.groups(),
+    )?;
+    shares_output(&groups, options.abbreviate)
+}
+
+// Helper to print groups of shares, groups separated by a blank line
+fn shares_output(
+    groups: &[Vec<Share>],
+    abbreviate: bool,
+) -> Result<String, slip39_calculator::Error> {
+    let groups = groups
+        .iter()
+        .map(|shares| {
+            shares
+                .iter()
+                .map(|share| {
+                    if abbreviate {
+                        share.to_abbreviations()
+                    } else {
+                        share.to_mnemonic()
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|mnemonics| mnemonics.join("\n"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(groups.join("\n\n"))
+}
+
+// Helper to describe the BIP-32 wallet of a seed by its fingerprint and an xpub
+fn bip32_output(
+    seed: &[u8],
+    fingerprint: bool,
+    xpub: Option<&DerivationPath>,
+) -> Result<Vec<String>, slip39_calculator::Error> {
+    let mut lines = Vec::new();
+    if !fingerprint && xpub.is_none() {
+        return Ok(lines);
+    }
+
+    let master = ExtendedPrivateKey::from_seed(seed)?;
+    if fingerprint {
+        lines.push(format!(
+            "master fingerprint: {}",
+            hex::encode(master.fingerprint())
+        ));
+    }
+    if let Some(path) = xpub {
+        let key = master.derive_path(path)?;
+        lines.push(format!("xpub at {}: {}", path, key.to_xpub()));
+    }
+    Ok(lines)
+}
+
+// Helper to parse every share of the input, failing on the first invalid one
+fn parse_shares(text: &str) -> Result<Vec<Share>, slip39_calculator::Error> {
+    parse_mnemonics(text)
+        .into_iter()
+        .map(|parsed| Ok(parsed?.to_share(text)?))
+        .collect()
+}
+
+// Helper to describe the single-word corrections of a share
+fn repair(mnemonic: &str, transpositions: bool) -> Result<String, slip39_calculator::Error> {
+    let words: Vec<&str> = tokenize(mnemonic).iter().map(|t| t.text).collect();
+    if words.contains(&ERASURE_MARKER) {
+        return fill_erasures_output(&words);
+    }
+
+    let mnemonic = words.join(" ");
+    if Share::from_mnemonic(&mnemonic).is_ok() {
+        return Ok("Share is valid, no repair needed".to_string());
+    }
+
+    let corrections = repair_mnemonic(&mnemonic, transpositions)?;
+    if corrections.is_empty() {
+        return Err(slip39_calculator::Error::InvalidShare(
+            "no single-word correction produces a valid checksum".to_string(),
+        ));
+    }
+
+    let output = corrections
+        .iter()
+        .map(|correction| {
+            let description = match correction.kind {
+                CorrectionKind::Substitution {
+                    position,
+                    replacement,
+                } => format!(
+                    "word {}: {} -> {}",
+                    position + 1,
+                    words[position],
+                    wordlist()[usize::from(replacement)]
+                ),
+                CorrectionKind::Transposition { position } => format!(
+                    "words {}-{}: swap {} and {}",
+                    position + 1,
+                    position + 2,
+                    words[position],
+                    words[position + 1]
+                ),
+            };
+            format!("{}\n{}", description, correction.to_mnemonic())
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    Ok(output)
+}
+
+// Helper to describe the completions of a share with erased words
+fn fill_erasures_output(words: &[&str]) -> Result<String, slip39_calculator::Error> {
+    let completions = fill_mnemonic_erasures(&words.join(" "))?;
+    if completions.is_empty() {
+        return Err(slip39_calculator::Error::InvalidShare(
+            "no words for the erased positions produce a valid checksum".to_string(),
+        ));
+    }
+
+    let output = completions
+        .iter()
+        .map(|indices| {
+            let mut lines: Vec<String> = words
+                .iter()
+                .enumerate()
+                .filter(|(_, &word)| word == ERASURE_MARKER)
+                .map(|(position, _)| {
+                    format!(
+                        "word {}: {}",
+                        position + 1,
+                        wordlist()[usize::from(indices[position])]
+                    )
+                })
+                .collect();
+            lines.push(
+                indices
+                    .iter()
+                    .map(|&i| wordlist()[usize::from(i)])
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    Ok(output)
+}
+
+// Helper to describe the recovery progress of a collection of mnemonics
+fn status(text: &str) -> String {
+    let mut shares = Vec::new();
+    let mut invalid = Vec::new();
+    for (position, parsed) in parse_mnemonics(text).into_iter().enumerate() {
+        match parsed.and_then(|parsed| parsed.to_share(text)) {
+            Ok(share) => shares.push(share),
+            Err(e) => invalid.push(format!(
+                "  share {}: {}",
+                position + 1,
+                describe_error(&e.into())
+            )),
+        }
+    }
+
+    let mut sections: Vec<String> = share_status(&shares)
+        .iter()
+        .map(|set| {
+            let mut lines = vec![format!(
+                "Share set {} (extendable: {}, iteration exponent: {})",
+                set.identifier,
+                if set.extendable { "yes" } else { "no" },
+                set.iteration_exponent
+            )];
+            for group in &set.groups {
+                let progress = if group.is_complete() {
+                    "complete".to_string()
+                } else {
+                    format!("needs {} more", group.members_needed())
+                };
+                lines.push(format!(
+                    "  group {}: {} of {} members ({})",
+                    group.group_index,
+                    group.member_indices.len(),
+                    group.member_threshold,
+                    progress
+                ));
+            }
+            let missing = set.missing_groups();
+            if !missing.is_empty() {
+                let indices: Vec<String> = missing.iter().map(|i| i.to_string()).collect();
+                let noun = if indices.len() == 1 {
+                    "group"
+                } else {
+                    "groups"
+                };
+                lines.push(format!("  no shares for {} {}", noun, indices.join(", ")));
+            }
+            for conflict in &set.conflicts {
+                lines.push(format!("  conflict: {}", conflict));
+            }
+            let progress = match (set.groups_needed(), set.conflicts.is_empty()) {
+                (0, true) => "ready to recover".to_string(),
+                (0, false) => "thresholds met, but conflicting shares must be removed".to_string(),
+                (needed, _) => format!("needs {} more", needed),
+            };
+            lines.push(format!(
+                "  {} of {} required groups complete: {}",
+                set.complete_groups(),
+                set.group_threshold,
+                progress
+            ));
+            lines.join("\n")
+        })
+        .collect();
+
+    if !invalid.is_empty() {
+        sections.push(format!("Invalid shares\n{}", invalid.join("\n")));
+    }
+    if sections.is_empty() {
+        return "No shares given".to_string();
+    }
+    sections.join("\n\n")
+}
+
+// Helper to break a full share down into its words and fields
+fn explain_share(words: &[&str], prefix: bool) -> Result<String, slip39_calculator::Error> {
+    let words = words
+        .iter()
+        .map(|w| find_word(w, prefix))
+        .collect::<Result<Vec<_>, _>>()?;
+    let indices: Vec<u16> = words.iter().map(|w| w.value()).collect();
+    let fields = layout(indices.len())?;
+
+    let mut lines = vec![format!(
+        "Share of {} words ({} bits)",
+        words.len(),
+        words.len() * 10
+    )];
+    lines.push(String::new());
+    lines.push(format!(
+        "{:>5}  {:<10} {:>5}  {}",
+        "word", "", "index", "bits"
+    ));
+    for (position, index) in words.iter().enumerate() {
+        lines.push(format!(
+            "{:>5}  {:<10} {:>5}  {}",
+            position + 1,
+            index.word(),
+            index,
+            index.to_bits()
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{:<20} {:>9}  {:>7}  {}",
+        "field", "bits", "words", "value"
+    ));
+    for field in &fields {
+        let span = |first: usize, last: usize| {
+            if first == last {
+                first.to_string()
+            } else {
+                format!("{}-{}", first, last)
+            }
+        };
+        let (first_word, last_word) = field.word_span();
+        let value = match field.name {
+            "extendable" => match field.read(&indices) {
+                0 => "0 (no)".to_string(),
+                _ => "1 (yes)".to_string(),
+            },
+            "group threshold" | "group count" | "member threshold" => {
+                let raw = field.read(&indices);
+                format!("{} (stored as {})", raw + 1, raw)
+            }
+            "padding" => match field.read(&indices) {
+                0 => "0".to_string(),
+                raw => format!("{:b} (invalid, must be zero)", raw),
+            },
+            "share value" => hex::encode(field.read_bytes(&indices)),
+            "checksum" => {
+                let extendable = indices[1] >> 4 & 1 == 1;
+                let valid = verify_checksum(&indices, Customization::from_extendable(extendable));
+                let checksum: Vec<String> = indices[indices.len() - 3..]
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect();
+                format!(
+                    "{} ({})",
+                    checksum.join(" "),
+                    if valid { "valid" } else { "invalid" }
+                )
+            }
+            _ => field.read(&indices).to_string(),
+        };
+        lines.push(format!(
+            "{:<20} {:>9}  {:>7}  {}",
+            field.name,
+            span(field.offset, field.offset + field.bits - 1),
+            span(first_word + 1, last_word + 1),
+            value
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+// Helper to break a full BIP-39 mnemonic down into its entropy and checksum
+fn explain_bip39(words: &[&str], prefix: bool) -> Result<String, slip39_calculator::Error> {
+    let indices = words
+        .iter()
+        .map(|w| find_bip39_word(w, prefix))
+        .collect::<Result<Vec<_>, _>>()?;
+    if !(12..=24).contains(&indices.len()) || !indices.len().is_multiple_of(3) {
+        return Err(slip39_calculator::Error::InvalidBip39MnemonicLength(
+            indices.len(),
+        ));
+    }
+
+    let mut lines = vec![format!(
+        "BIP-39 mnemonic of {} words ({} bits)",
+        indices.len(),
+        indices.len() * bip39::RADIX_BITS
+    )];
+    lines.push(String::new());
+    lines.push(format!(
+        "{:>5}  {:<10} {:>5}  {}",
+        "word", "", "index", "bits"
+    ));
+    for (position, &index) in indices.iter().enumerate() {
+        lines.push(format!(
+            "{:>5}  {:<10} {:>5}  {:011b}",
+            position + 1,
+            bip39::wordlist()[usize::from(index)],
+            index,
+            index
+        ));
+    }
+
+    // Every 3 words hold 32 bits of entropy and 1 bit of checksum
+    let bits: String = indices.iter().map(|i| format!("{:011b}", i)).collect();
+    let (entropy_bits, checksum_bits) = bits.split_at(indices.len() / 3 * 32);
+    let entropy: Vec<u8> = entropy_bits
+        .as_bytes()
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| acc << 1 | (bit - b'0')))
+        .collect();
+    let expected = bip39::entropy_to_mnemonic(&entropy)?;
+    let expected_last = bip39::word_to_index(expected.rsplit(' ').next().unwrap_or(""))?;
+    let expected_bits = format!("{:011b}", expected_last);
+    let expected_bits = &expected_bits[bip39::RADIX_BITS - checksum_bits.len()..];
+
+    lines.push(String::new());
+    lines.push(format!("{:<9} {:>9}  {}", "field", "bits", "value"));
+    lines.push(format!(
+        "{:<9} {:>9}  {}",
+        "entropy",
+        format!("0-{}", entropy_bits.len() - 1),
+        hex::encode(&entropy)
+    ));
+    let status = if checksum_bits == expected_bits {
+        "valid".to_string()
+    } else {
+        format!("invalid, expected {}", expected_bits)
+    };
+    lines.push(format!(
+        "{:<9} {:>9}  {} ({})",
+        "checksum",
+        format!("{}-{}", entropy_bits.len(), bits.len() - 1),
+        checksum_bits,
+        status
+    ));
+    Ok(lines.join("\n"))
+}
+
+// Helper to format an error, with the location of input errors and wallet mismatch hints
+fn describe_error(error: &slip39_calculator::Error) -> String {
+    match error {
+        slip39_calculator::Error::FingerprintMismatch(_, _) => {
+            return format!(
+                "{} (a wrong passphrase silently restores a different wallet)",
+                error
+            )
+        }
+        slip39_calculator::Error::InvalidInput {
+            line,
+            column,
+            source,
+        } => {
+            return format!(
+                "Line {}, column {}: {}",
+                line,
+                column,
+                describe_error(source)
+            )
+        }
+        _ => {}
+    }
+    error.to_string()
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -134,39 +865,64 @@ fn main() {
         }
         Some(command) => {
             let result: Result<String, slip39_calculator::Error> = match command {
-                Commands::EncodeWord { word, prefix } => {
-                    find_word(&word, prefix).and_then(|w| encode(&w))
-                }
+                Commands::EncodeWord {
+                    word,
+                    prefix,
+                    scheme,
+                } => match scheme {
+                    Scheme::Slip39 => find_word(&word, prefix).map(WordIndex::to_bits),
+                    Scheme::Bip39 => {
+                        find_bip39_word(&word, prefix).map(|index| format!("{:011b}", index))
+                    }
+                },
 
-                Commands::DecodeBits { binary } => decode(&binary),
+                Commands::DecodeBits { binary, scheme } => match scheme {
+                    Scheme::Slip39 => decode(&binary),
+                    Scheme::Bip39 => bip39::decode(&binary),
+                },
 
-                Commands::WordToIndex { word, prefix } => find_word(&word, prefix).and_then(|w| {
-                    wordlist()
-                        .iter()
-                        .position(|&list_word| list_word == w)
-                        .map(|index| index.to_string())
-                        .ok_or(slip39_calculator::Error::WordNotFound(w))
-                }),
+                Commands::WordToIndex {
+                    word,
+                    prefix,
+                    scheme,
+                } => match scheme {
+                    Scheme::Slip39 => find_word(&word, prefix).map(|index| index.to_string()),
+                    Scheme::Bip39 => find_bip39_word(&word, prefix).map(|index| index.to_string()),
+                },
 
-                Commands::IndexToWord { index } => {
-                    if index < wordlist().len() {
-                        Ok(wordlist()[index].to_string())
-                    } else {
-                        Err(slip39_calculator::Error::InvalidBinaryLength(index))
+                Commands::IndexToWord { index, scheme } => match scheme {
+                    Scheme::Slip39 => index
+                        .parse::<WordIndex>()
+                        .map(|index| index.word().to_string()),
+                    Scheme::Bip39 => bip39::parse_index(&index)
+                        .map(|index| bip39::wordlist()[usize::from(index)].to_string()),
+                },
+
+                Commands::Explain {
+                    word,
+                    prefix,
+                    scheme,
+                } => {
+                    let text = word.join(" ");
+                    let words: Vec<&str> = tokenize(&text).iter().map(|t| t.text).collect();
+                    let word = words.first().copied().unwrap_or("");
+                    match (scheme, words.len() > 1) {
+                        (Scheme::Slip39, true) => explain_share(&words, prefix),
+                        (Scheme::Slip39, false) => find_word(word, prefix).map(|index| {
+                            format!("{} -> {} -> {}", index.word(), index, index.to_bits())
+                        }),
+                        (Scheme::Bip39, true) => explain_bip39(&words, prefix),
+                        (Scheme::Bip39, false) => find_bip39_word(word, prefix).map(|index| {
+                            format!(
+                                "{} -> {} -> {:011b}",
+                                bip39::wordlist()[usize::from(index)],
+                                index,
+                                index
+                            )
+                        }),
                     }
                 }
 
-                Commands::Explain { word, prefix } => find_word(&word, prefix).and_then(|w| {
-                    let index = wordlist()
-                        .iter()
-                        .position(|&list_word| list_word == w)
-                        .unwrap();
-
-                    let bits = encode(&w)?;
-
-                    Ok(format!("{} -> {} -> {}", w, index, bits))
-                }),
-
                 Commands::Generate { count } => {
                     let mut output = String::new();
                     for i in 0..count {
@@ -179,13 +935,113 @@ fn main() {
                     Ok(output)
                 }
 
+                Commands::Split { secret, options } => {
+                    parse_hex(&secret).and_then(|secret| split_output(&secret, &options))
+                }
+
+                Commands::FromBip39 { mnemonic, options } => {
+                    let text = mnemonic.join(" ");
+                    let words: Vec<&str> = tokenize(&text).iter().map(|t| t.text).collect();
+                    bip39::mnemonic_to_entropy(&words.join(" "))
+                        .and_then(|entropy| split_output(&entropy, &options))
+                }
+
+                Commands::ToBip39 {
+                    mnemonics,
+                    file,
+                    passphrase,
+                } => {
+                    let text = read_mnemonics(mnemonics, file);
+                    parse_shares(&text)
+                        .and_then(|shares| combine(&shares, passphrase.as_bytes()))
+                        .and_then(|entropy| bip39::entropy_to_mnemonic(&entropy))
+                }
+
+                Commands::Combine {
+                    mnemonics,
+                    file,
+                    passphrase,
+                    fingerprint,
+                    xpub,
+                } => {
+                    let text = read_mnemonics(mnemonics, file);
+                    parse_shares(&text)
+                        .and_then(|shares| combine(&shares, passphrase.as_bytes()))
+                        .and_then(|secret| {
+                            let mut lines = vec![hex::encode(&secret)];
+                            lines.extend(bip32_output(&secret, fingerprint, xpub.as_ref())?);
+                            Ok(lines.join("\n"))
+                        })
+                }
+
+                Commands::Verify {
+                    mnemonics,
+                    file,
+                    passphrase,
+                    fingerprint,
+                    bip39,
+                    bip39_passphrase,
+                } => {
+                    let text = read_mnemonics(mnemonics, file);
+                    parse_shares(&text)
+                        .and_then(|shares| combine(&shares, passphrase.as_bytes()))
+                        .and_then(|secret| {
+                            if bip39 {
+                                let mnemonic = bip39::entropy_to_mnemonic(&secret)?;
+                                Ok(bip39::mnemonic_to_seed(&mnemonic, &bip39_passphrase)?.to_vec())
+                            } else {
+                                Ok(secret)
+                            }
+                        })
+                        .and_then(|seed| verify_fingerprint(&seed, &fingerprint))
+                        .map(|()| {
+                            format!(
+                                "Fingerprint {} matches: the shares restore the expected wallet",
+                                hex::encode(fingerprint)
+                            )
+                        })
+                }
+
+                Commands::Reshare {
+                    mnemonics,
+                    file,
+                    layout,
+                    reuse_identifier,
+                    abbreviate,
+                } => {
+                    let text = read_mnemonics(mnemonics, file);
+                    parse_shares(&text)
+                        .and_then(|shares| {
+                            reshare(
+                                &shares,
+                                layout.group_threshold,
+                                &layout.groups(),
+                                reuse_identifier,
+                            )
+                        })
+                        .and_then(|groups| shares_output(&groups, abbreviate))
+                }
+
+                Commands::Repair {
+                    mnemonic,
+                    transpositions,
+                } => {
+                    let mnemonic = mnemonic.join(" ");
+                    repair(&mnemonic, transpositions)
+                }
+
+                Commands::Status { mnemonics, file } => {
+                    let text = read_mnemonics(mnemonics, file);
+                    Ok(status(&text))
+                }
+
                 Commands::Tui { .. } => unreachable!(), // Handled above
             };
 
             match result {
                 Ok(output) => println!("{}", output),
                 Err(error) => {
-                    eprintln!("Error: {}", error);
+                    eprintln!("Error: {}", describe_error(&error));
                     process::exit(1);
                 }
             }
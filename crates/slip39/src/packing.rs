@@ -0,0 +1,164 @@
+//! Packing of byte strings into 10-bit word indices
+//!
+//! Bytes are read as one big-endian bit string and cut into 10-bit words. When
+//! the bit length is not a multiple of 10, the string is left-padded with zero
+//! bits, which is how SLIP-39 stores the share value.
+
+use crate::Error;
+
+/// Number of bits encoded by each word
+pub const RADIX_BITS: usize = 10;
+
+/// Number of words needed to hold `byte_len` bytes
+pub fn word_count(byte_len: usize) -> usize {
+    (byte_len * 8).div_ceil(RADIX_BITS)
+}
+
+/// Pack bytes into 10-bit word indices, left-padding with zero bits
+///
+/// # Arguments
+/// * `bytes` - The bytes to pack
+///
+/// # Returns
+/// * `Vec<u16>` - [`word_count`] word indices (0-1023)
+///
+/// # Example
+/// ```
+/// use slip39_calculator::bytes_to_indices;
+///
+/// // 16 bits are left-padded to 20 bits
+/// assert_eq!(bytes_to_indices(&[0xFF, 0xFF]), vec![0x03F, 0x3FF]);
+/// ```
+pub fn bytes_to_indices(bytes: &[u8]) -> Vec<u16> {
+    let word_count = word_count(bytes.len());
+    let padding_bits = word_count * RADIX_BITS - bytes.len() * 8;
+
+    let mut indices = Vec::with_capacity(word_count);
+    let mut acc: u32 = 0;
+    let mut acc_bits = padding_bits;
+    for &byte in bytes {
+        acc = (acc << 8) | u32::from(byte);
+        acc_bits += 8;
+        while acc_bits >= RADIX_BITS {
+            acc_bits -= RADIX_BITS;
+            indices.push(((acc >> acc_bits) & 0x3FF) as u16);
+        }
+    }
+    indices
+}
+
+/// Unpack 10-bit word indices into bytes, rejecting non-zero padding
+///
+/// The byte length must be given because a word count can hold more than one
+/// byte length (40 bits hold 5 bytes, or 4 bytes with 8 bits of padding).
+///
+/// # Arguments
+/// * `indices` - Word indices (0-1023)
+/// * `byte_len` - Number of bytes to unpack; the remaining leading bits are padding
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The unpacked bytes
+/// * `Err(Error::InvalidParameters)` - If `byte_len` does not leave 0-9 bits of padding
+/// * `Err(Error::IndexOutOfRange)` - If an index is not below 1024
+/// * `Err(Error::InvalidPadding)` - If any padding bit is set
+pub fn indices_to_bytes(indices: &[u16], byte_len: usize) -> Result<Vec<u8>, Error> {
+    let total_bits = indices.len() * RADIX_BITS;
+    let padding_bits = total_bits
+        .checked_sub(byte_len * 8)
+        .filter(|&padding| padding < RADIX_BITS)
+        .ok_or_else(|| {
+            Error::InvalidParameters(format!(
+                "{} words cannot hold exactly {} bytes",
+                indices.len(),
+                byte_len
+            ))
+        })?;
+    if let Some(&index) = indices.iter().find(|&&index| index >= 1 << RADIX_BITS) {
+        return Err(Error::IndexOutOfRange(usize::from(index)));
+    }
+
+    let mut bytes = Vec::with_capacity(byte_len);
+    let mut acc: u32 = 0;
+    let mut acc_bits = 0;
+    let mut skip = padding_bits;
+    for &index in indices {
+        acc = (acc << RADIX_BITS) | u32::from(index);
+        acc_bits += RADIX_BITS;
+        if skip > 0 {
+            acc_bits -= skip;
+            if acc >> acc_bits != 0 {
+                return Err(Error::InvalidPadding);
+            }
+            acc &= (1 << acc_bits) - 1;
+            skip = 0;
+        }
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes.push((acc >> acc_bits) as u8);
+            acc &= (1 << acc_bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_every_length() {
+        for len in 0..=40 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+            let indices = bytes_to_indices(&bytes);
+            assert_eq!(indices.len(), word_count(len));
+            assert_eq!(
+                indices_to_bytes(&indices, len).unwrap(),
+                bytes,
+                "{} bytes",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_padding_is_left_aligned() {
+        // 8 bits are stored in one word with 2 leading zero bits
+        assert_eq!(bytes_to_indices(&[0xFF]), vec![0x0FF]);
+        assert_eq!(indices_to_bytes(&[0x0FF], 1).unwrap(), vec![0xFF]);
+        // 40 bits hold either 5 bytes or 4 bytes with 8 bits of padding
+        assert_eq!(
+            indices_to_bytes(&[0, 0, 0, 1], 5).unwrap(),
+            vec![0, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            indices_to_bytes(&[0, 0, 0, 1], 4).unwrap(),
+            vec![0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_non_zero_padding_rejected() {
+        let mut indices = bytes_to_indices(&[0xFF; 16]);
+        indices[0] |= 0x200;
+        assert!(matches!(
+            indices_to_bytes(&indices, 16),
+            Err(Error::InvalidPadding)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_arguments_rejected() {
+        assert!(matches!(
+            indices_to_bytes(&[0, 0], 3),
+            Err(Error::InvalidParameters(_))
+        ));
+        assert!(matches!(
+            indices_to_bytes(&[0, 0, 0], 2),
+            Err(Error::InvalidParameters(_))
+        ));
+        assert!(matches!(
+            indices_to_bytes(&[0, 1024], 2),
+            Err(Error::IndexOutOfRange(1024))
+        ));
+    }
+}
@@ -0,0 +1,268 @@
+//! Parser for mnemonics pasted from notes, scans and password managers
+//!
+//! The parser accepts:
+//! - words separated by any whitespace, commas or semicolons, in any case
+//! - numbering before words ("1. acid", "2) acne", "#3 acquire", "4.acrobat")
+//! - four-letter abbreviations and other unique prefixes
+//! - several shares separated by blank lines, or one share per line when every
+//!   line of a paragraph holds at least a minimum-length share
+//!
+//! Every word keeps the byte range it was read from, so that errors point at
+//! the exact position in the input.
+
+use crate::share::{Share, MIN_MNEMONIC_LENGTH_WORDS};
+use crate::word_index::WordIndex;
+use crate::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// A word-like token of the input, with numbering and punctuation removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The token text
+    pub text: &'a str,
+    /// Byte range of the token in the input
+    pub span: Range<usize>,
+}
+
+/// A word of a parsed mnemonic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedWord {
+    /// The resolved word
+    pub index: WordIndex,
+    /// Byte range of the word as written in the input
+    pub span: Range<usize>,
+}
+
+/// A mnemonic found in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedMnemonic {
+    /// The words, in order
+    pub words: Vec<ParsedWord>,
+    /// Byte range from the first to the last word in the input
+    pub span: Range<usize>,
+}
+
+impl ParsedMnemonic {
+    /// Word indices of the mnemonic
+    pub fn indices(&self) -> Vec<u16> {
+        self.words.iter().map(|word| word.index.value()).collect()
+    }
+
+    /// Decode the mnemonic as a share
+    ///
+    /// # Arguments
+    /// * `text` - The input the mnemonic was parsed from, to locate errors
+    ///
+    /// # Returns
+    /// * `Ok(Share)` - The decoded share
+    /// * `Err(ParseError)` - See [`Share::from_indices`], located at the start of the mnemonic
+    pub fn to_share(&self, text: &str) -> Result<Share, ParseError> {
+        Share::from_indices(&self.indices())
+            .map_err(|error| ParseError::new(text, self.span.clone(), error))
+    }
+}
+
+/// An error located in the parsed input
+#[derive(Debug)]
+pub struct ParseError {
+    /// Byte range of the offending text
+    pub span: Range<usize>,
+    /// Line of the start of the span (1-based)
+    pub line: usize,
+    /// Column of the start of the span, in characters (1-based)
+    pub column: usize,
+    /// The underlying error
+    pub error: Error,
+}
+
+impl ParseError {
+    fn new(text: &str, span: Range<usize>, error: Error) -> Self {
+        let before = &text[..span.start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = text[line_start..span.start].chars().count() + 1;
+        ParseError {
+            span,
+            line,
+            column,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.error
+        )
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::InvalidInput {
+            line: error.line,
+            column: error.column,
+            source: Box::new(error.error),
+        }
+    }
+}
+
+/// Split text into word tokens, dropping separators and numbering
+///
+/// Separators are whitespace, commas and semicolons. A token made only of a
+/// number ("1", "2.", "3)", "#4", "5:") is numbering and dropped; numbering
+/// glued to a word ("6.acid") is stripped, as are trailing periods and colons.
+///
+/// # Example
+/// ```
+/// use slip39_calculator::parser::tokenize;
+///
+/// let tokens: Vec<&str> = tokenize("1. Acid, 2) acne\t#3 acquire.").iter().map(|t| t.text).collect();
+/// assert_eq!(tokens, ["Acid", "acne", "acquire"]);
+/// ```
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let is_separator = |c: char| c.is_whitespace() || c == ',' || c == ';';
+
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, is_separator(c)) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                if let Some(token) = clean_token(text, s..i) {
+                    tokens.push(token);
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Strip numbering and trailing punctuation from a raw token
+fn clean_token(text: &str, span: Range<usize>) -> Option<Token<'_>> {
+    let raw = &text[span.clone()];
+    let unnumbered = raw.strip_prefix('#').unwrap_or(raw);
+    let digits = unnumbered.len()
+        - unnumbered
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .len();
+
+    let mut start = span.start;
+    if digits > 0 {
+        let rest = &unnumbered[digits..];
+        let rest = rest.strip_prefix(['.', ')', ':']).unwrap_or(rest);
+        if rest.is_empty() {
+            return None;
+        }
+        // Only numbering directly followed by a separator character is stripped
+        if rest.len() < unnumbered.len() - digits {
+            start = span.end - rest.len();
+        }
+    }
+
+    let text = text[start..span.end].trim_end_matches(['.', ':']);
+    (!text.is_empty()).then(|| Token {
+        text,
+        span: start..start + text.len(),
+    })
+}
+
+/// Resolve the tokens of one mnemonic
+fn parse_tokens(text: &str, tokens: &[Token<'_>]) -> Result<ParsedMnemonic, ParseError> {
+    let words = tokens
+        .iter()
+        .map(|token| {
+            WordIndex::from_prefix_ct(token.text)
+                .map(|index| ParsedWord {
+                    index,
+                    span: token.span.clone(),
+                })
+                .map_err(|error| ParseError::new(text, token.span.clone(), error))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let span = match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => first.span.start..last.span.end,
+        _ => 0..0,
+    };
+    Ok(ParsedMnemonic { words, span })
+}
+
+/// Parse all the words of the input as a single mnemonic
+///
+/// # Returns
+/// * `Ok(ParsedMnemonic)` - The words of the mnemonic
+/// * `Err(ParseError)` - Locating the first word that cannot be resolved
+pub fn parse_mnemonic(text: &str) -> Result<ParsedMnemonic, ParseError> {
+    parse_tokens(text, &tokenize(text))
+}
+
+/// Parse any number of mnemonics from free-form text
+///
+/// Paragraphs are separated by blank lines. A paragraph is a single mnemonic,
+/// unless every one of its lines holds at least a minimum-length share, in
+/// which case each line is a mnemonic of its own.
+///
+/// # Returns
+/// * `Vec<Result<ParsedMnemonic, ParseError>>` - One entry per mnemonic, in input order
+///
+/// # Example
+/// ```
+/// use slip39_calculator::parser::parse_mnemonics;
+///
+/// let text = "1. duckling 2. enlarge 3. acad 4. acad 5. agency\n\
+///             6. result 7. length 8. solution 9. fridge 10. kidney\n\
+///             11. coal 12. piece 13. deal 14. husband 15. erode\n\
+///             16. duke 17. ajar 18. critical 19. decision 20. keyboard\n\
+///             \n\
+///             acid acne";
+/// let parsed = parse_mnemonics(text);
+/// assert_eq!(parsed.len(), 2);
+/// assert_eq!(parsed[0].as_ref().unwrap().words.len(), 20);
+/// assert_eq!(parsed[1].as_ref().unwrap().words.len(), 2);
+/// ```
+pub fn parse_mnemonics(text: &str) -> Vec<Result<ParsedMnemonic, ParseError>> {
+    let mut paragraphs: Vec<Vec<Vec<Token<'_>>>> = vec![Vec::new()];
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let tokens: Vec<Token<'_>> = tokenize(line)
+            .into_iter()
+            .map(|token| Token {
+                text: token.text,
+                span: token.span.start + offset..token.span.end + offset,
+            })
+            .collect();
+        offset += line.len();
+
+        if line.trim().is_empty() {
+            paragraphs.push(Vec::new());
+        } else if !tokens.is_empty() {
+            // Lines holding only numbering or punctuation do not end a paragraph
+            paragraphs.last_mut().unwrap().push(tokens);
+        }
+    }
+
+    paragraphs
+        .into_iter()
+        .filter(|lines| !lines.is_empty())
+        .flat_map(|lines| {
+            if lines.len() > 1 && lines.iter().all(|l| l.len() >= MIN_MNEMONIC_LENGTH_WORDS) {
+                lines
+            } else {
+                vec![lines.concat()]
+            }
+        })
+        .map(|tokens| parse_tokens(text, &tokens))
+        .collect()
+}
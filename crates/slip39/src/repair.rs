@@ -0,0 +1,316 @@
+//! Single-word error correction using the RS1024 checksum
+//!
+//! The RS1024 code has a minimum distance of 4, so a share with exactly one
+//! wrong word has at most one substitution that restores a valid checksum.
+//! Swapping two adjacent words is a common transcription mistake as well, so
+//! transpositions can be tried on request; those are not guaranteed to be
+//! unique and every match is reported.
+//!
+//! When the positions of the wrong words are known (illegible or missing
+//! words), the same code can fill in up to three of them. A single erasure is
+//! found by trying every word; two or three are solved directly, since the
+//! checksum residue is affine in the word bits and yields 30 equations over
+//! GF(2) for at most 30 unknown bits. Each customization has at most one
+//! solution, so three erasures that include the second word (which holds the
+//! extendable flag) can yield two valid completions.
+
+use crate::packing::RADIX_BITS;
+use crate::rs1024::{residue, Customization, CHECKSUM_LENGTH_WORDS};
+use crate::share::{layout, Share};
+use crate::word_index::WordIndex;
+use crate::{wordlist, Error};
+
+/// Number of words in the SLIP-39 wordlist
+const WORD_COUNT: u16 = 1024;
+
+/// Maximum number of erased words that the checksum can recover
+pub const MAX_ERASURES: usize = CHECKSUM_LENGTH_WORDS;
+
+/// Marker for an erased word in a mnemonic
+pub const ERASURE_MARKER: &str = "?";
+
+/// The edit applied to a mnemonic by a [`Correction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrectionKind {
+    /// The word at `position` is replaced by the word at index `replacement`
+    Substitution { position: usize, replacement: u16 },
+    /// The words at `position` and `position + 1` are swapped
+    Transposition { position: usize },
+}
+
+/// A candidate correction that turns a mnemonic into a valid share
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Correction {
+    /// The edit that was applied
+    pub kind: CorrectionKind,
+    /// Word indices of the corrected mnemonic
+    pub indices: Vec<u16>,
+}
+
+impl Correction {
+    /// The corrected mnemonic as space-separated words
+    pub fn to_mnemonic(&self) -> String {
+        self.indices
+            .iter()
+            .map(|&i| wordlist()[usize::from(i)])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Find every single-word correction of a share given as word indices
+///
+/// A candidate is reported when the corrected indices decode as a valid share
+/// (checksum, padding and header). A share that is already valid yields no
+/// candidates.
+///
+/// # Arguments
+/// * `indices` - Word indices (0-1023) of the mnemonic, including the checksum
+/// * `transpositions` - Also try swapping every pair of adjacent words
+///
+/// # Returns
+/// * `Vec<Correction>` - Substitutions in word order, then transpositions
+pub fn repair_indices(indices: &[u16], transpositions: bool) -> Vec<Correction> {
+    if Share::from_indices(indices).is_ok() {
+        return Vec::new();
+    }
+
+    let mut corrections: Vec<Correction> = (0..indices.len())
+        .flat_map(|position| substitutions(indices, position))
+        .collect();
+
+    if transpositions {
+        for position in 0..indices.len().saturating_sub(1) {
+            if indices[position] == indices[position + 1] {
+                continue;
+            }
+            let mut candidate = indices.to_vec();
+            candidate.swap(position, position + 1);
+            if Share::from_indices(&candidate).is_ok() {
+                corrections.push(Correction {
+                    kind: CorrectionKind::Transposition { position },
+                    indices: candidate,
+                });
+            }
+        }
+    }
+
+    corrections
+}
+
+/// Find every single-word correction of a share mnemonic
+///
+/// Words may be given as unique prefixes. One word that cannot be resolved is
+/// treated as the wrong word: only substitutions at its position are tried.
+///
+/// # Arguments
+/// * `mnemonic` - The share mnemonic
+/// * `transpositions` - Also try swapping every pair of adjacent words
+///
+/// # Returns
+/// * `Ok(Vec<Correction>)` - The candidate corrections (empty if none or if already valid)
+/// * `Err(Error::WordNotFound)` / `Err(Error::AmbiguousPrefix)` - If more than one word cannot be resolved
+/// * `Err(Error::InvalidMnemonicLength)` - If the word count cannot hold a valid share
+///
+/// # Example
+/// ```
+/// use slip39_calculator::repair::{repair_mnemonic, CorrectionKind};
+///
+/// // "academic" was misspelled as "academix"
+/// let share = "duckling enlarge academix academic agency result length solution fridge \
+///              kidney coal piece deal husband erode duke ajar critical decision keyboard";
+/// let corrections = repair_mnemonic(share, false).unwrap();
+/// assert_eq!(corrections.len(), 1);
+/// assert!(matches!(
+///     corrections[0].kind,
+///     CorrectionKind::Substitution { position: 2, .. }
+/// ));
+/// ```
+pub fn repair_mnemonic(mnemonic: &str, transpositions: bool) -> Result<Vec<Correction>, Error> {
+    let mut unresolved: Option<(usize, Error)> = None;
+    let mut indices = Vec::new();
+    for (position, word) in mnemonic.split_whitespace().enumerate() {
+        match WordIndex::from_prefix_ct(word) {
+            Ok(index) => indices.push(index.value()),
+            Err(error) => {
+                if let Some((_, first)) = unresolved {
+                    return Err(first);
+                }
+                unresolved = Some((position, error));
+                // Out-of-range placeholder, so that every word is tried in its place
+                indices.push(WORD_COUNT);
+            }
+        }
+    }
+
+    // Substitutions never change the length, so a wrong length cannot be repaired
+    layout(indices.len())?;
+
+    match unresolved {
+        Some((position, _)) => Ok(substitutions(&indices, position).collect()),
+        None => Ok(repair_indices(&indices, transpositions)),
+    }
+}
+
+/// Fill in erased words so that the indices form a valid share
+///
+/// # Arguments
+/// * `indices` - Word indices of the mnemonic, `None` for every erased word
+///
+/// # Returns
+/// * `Ok(Vec<Vec<u16>>)` - Every completion that decodes as a valid share (at most one per customization)
+/// * `Err(Error::InvalidParameters)` - If more than three words are erased
+/// * `Err(Error::InvalidMnemonicLength)` - If the word count cannot hold a valid share
+pub fn fill_erasures(indices: &[Option<u16>]) -> Result<Vec<Vec<u16>>, Error> {
+    let positions: Vec<usize> = (0..indices.len())
+        .filter(|&i| indices[i].is_none())
+        .collect();
+    if positions.len() > MAX_ERASURES {
+        return Err(Error::InvalidParameters(format!(
+            "{} erased words, at most {} can be recovered",
+            positions.len(),
+            MAX_ERASURES
+        )));
+    }
+
+    layout(indices.len())?;
+    let known: Vec<u16> = indices.iter().map(|i| i.unwrap_or(0)).collect();
+
+    let candidates: Vec<Vec<u16>> = match positions.as_slice() {
+        [] => vec![known],
+        [position] => (0..WORD_COUNT)
+            .map(|word| {
+                let mut candidate = known.clone();
+                candidate[*position] = word;
+                candidate
+            })
+            .collect(),
+        // The customization depends on the extendable flag, which may itself be erased
+        _ => [Customization::Shamir, Customization::ShamirExtendable]
+            .into_iter()
+            .filter_map(|customization| solve_erasures(&known, &positions, customization))
+            .collect(),
+    };
+
+    Ok(candidates
+        .into_iter()
+        .filter(|candidate| Share::from_indices(candidate).is_ok())
+        .collect())
+}
+
+/// Fill in the words marked with `?` in a share mnemonic
+///
+/// Words may be given as unique prefixes.
+///
+/// # Arguments
+/// * `mnemonic` - The share mnemonic, with [`ERASURE_MARKER`] in place of up to three words
+///
+/// # Returns
+/// * `Ok(Vec<Vec<u16>>)` - Every completion that decodes as a valid share
+/// * `Err(Error::WordNotFound)` / `Err(Error::AmbiguousPrefix)` - If a word cannot be resolved
+/// * `Err(Error)` - See [`fill_erasures`]
+///
+/// # Example
+/// ```
+/// use slip39_calculator::repair::fill_mnemonic_erasures;
+///
+/// let share = "duckling enlarge academic ? agency result length solution fridge \
+///              kidney ? piece deal husband erode duke ajar ? decision keyboard";
+/// let completions = fill_mnemonic_erasures(share).unwrap();
+/// assert_eq!(completions.len(), 1);
+/// ```
+pub fn fill_mnemonic_erasures(mnemonic: &str) -> Result<Vec<Vec<u16>>, Error> {
+    let indices = mnemonic
+        .split_whitespace()
+        .map(|word| {
+            if word == ERASURE_MARKER {
+                return Ok(None);
+            }
+            WordIndex::from_prefix_ct(word).map(|index| Some(index.value()))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    fill_erasures(&indices)
+}
+
+/// Solve the checksum equations for the words at `positions`
+///
+/// `known` holds zero at every erased position. Returns `None` when the
+/// system has no solution for this customization.
+fn solve_erasures(
+    known: &[u16],
+    positions: &[usize],
+    customization: Customization,
+) -> Option<Vec<u16>> {
+    let base = residue(known, customization);
+
+    // Contribution of every unknown bit to the residue (the linear part)
+    let columns: Vec<u32> = positions
+        .iter()
+        .flat_map(|&position| (0..RADIX_BITS).map(move |bit| (position, bit)))
+        .map(|(position, bit)| {
+            let mut probe = known.to_vec();
+            probe[position] ^= 1 << bit;
+            residue(&probe, customization) ^ base
+        })
+        .collect();
+
+    // One equation per residue bit: the unknown bits must turn `base` into 1
+    let target = base ^ 1;
+    let mut rows: Vec<(u32, bool)> = (0..RADIX_BITS * CHECKSUM_LENGTH_WORDS)
+        .map(|i| {
+            let mask = columns
+                .iter()
+                .enumerate()
+                .filter(|(_, &column)| (column >> i) & 1 == 1)
+                .fold(0u32, |mask, (k, _)| mask | (1 << k));
+            (mask, (target >> i) & 1 == 1)
+        })
+        .collect();
+
+    // Gauss-Jordan elimination over GF(2)
+    let mut pivots = Vec::with_capacity(columns.len());
+    for k in 0..columns.len() {
+        let rank = pivots.len();
+        // The code is MDS, so up to three erasures always give a full-rank system
+        let pivot = (rank..rows.len()).find(|&r| (rows[r].0 >> k) & 1 == 1)?;
+        rows.swap(rank, pivot);
+        let (mask, rhs) = rows[rank];
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && (row.0 >> k) & 1 == 1 {
+                row.0 ^= mask;
+                row.1 ^= rhs;
+            }
+        }
+        pivots.push(k);
+    }
+    if rows[pivots.len()..].iter().any(|&(_, rhs)| rhs) {
+        return None;
+    }
+
+    let mut solution = known.to_vec();
+    for (rank, &k) in pivots.iter().enumerate() {
+        if rows[rank].1 {
+            solution[positions[k / RADIX_BITS]] |= 1 << (k % RADIX_BITS);
+        }
+    }
+    Some(solution)
+}
+
+/// Every replacement of the word at `position` that yields a valid share
+fn substitutions(indices: &[u16], position: usize) -> impl Iterator<Item = Correction> + '_ {
+    (0..WORD_COUNT)
+        .filter(move |&replacement| replacement != indices[position])
+        .filter_map(move |replacement| {
+            let mut candidate = indices.to_vec();
+            candidate[position] = replacement;
+            Share::from_indices(&candidate)
+                .is_ok()
+                .then_some(Correction {
+                    kind: CorrectionKind::Substitution {
+                        position,
+                        replacement,
+                    },
+                    indices: candidate,
+                })
+        })
+}
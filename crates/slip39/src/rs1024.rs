@@ -0,0 +1,146 @@
+//! RS1024 checksum used by SLIP-39 mnemonics
+//!
+//! Every SLIP-39 share ends with three checksum words (30 bits) computed with a
+//! Reed-Solomon code over GF(1024). The checksum is salted with a customization
+//! string that depends on the extendable backup flag of the share.
+
+/// Number of checksum words appended to every mnemonic
+pub const CHECKSUM_LENGTH_WORDS: usize = 3;
+
+/// Generator constants of the RS1024 code (from the SLIP-39 specification)
+const GEN: [u32; 10] = [
+    0x00E0_E040,
+    0x01C1_C080,
+    0x0383_8100,
+    0x0707_0200,
+    0x0E0E_0009,
+    0x1C0C_2412,
+    0x3808_6C24,
+    0x3090_FC48,
+    0x21B1_F890,
+    0x03F3_F120,
+];
+
+/// Customization string mixed into the checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Customization {
+    /// `"shamir"`, used by non-extendable shares
+    Shamir,
+    /// `"shamir_extendable"`, used by extendable shares
+    ShamirExtendable,
+}
+
+impl Customization {
+    /// Select the customization string for the given extendable flag
+    pub fn from_extendable(extendable: bool) -> Self {
+        if extendable {
+            Customization::ShamirExtendable
+        } else {
+            Customization::Shamir
+        }
+    }
+
+    /// The raw customization string
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Customization::Shamir => b"shamir",
+            Customization::ShamirExtendable => b"shamir_extendable",
+        }
+    }
+}
+
+/// Compute the RS1024 polynomial remainder of a sequence of 10-bit values
+fn polymod(values: impl IntoIterator<Item = u32>) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 20;
+        chk = ((chk & 0xF_FFFF) << 10) ^ value;
+        for (i, gen) in GEN.iter().enumerate() {
+            // Branch-free select: mask is all ones when bit i of `top` is set
+            let mask = 0u32.wrapping_sub((top >> i) & 1);
+            chk ^= gen & mask;
+        }
+    }
+    chk
+}
+
+/// Feed the customization string followed by the word indices into `polymod`
+fn polymod_with(customization: Customization, indices: &[u16], tail: &[u16]) -> u32 {
+    let custom = customization.as_bytes().iter().map(|&b| u32::from(b));
+    let data = indices.iter().chain(tail).map(|&i| u32::from(i));
+    polymod(custom.chain(data))
+}
+
+/// Compute the three checksum words for a sequence of word indices
+///
+/// # Arguments
+/// * `indices` - Word indices (0-1023) of the mnemonic, without the checksum
+/// * `customization` - Customization string selected by the extendable flag
+///
+/// # Returns
+/// * `[u16; 3]` - The checksum word indices to append
+pub fn create_checksum(indices: &[u16], customization: Customization) -> [u16; 3] {
+    let remainder = polymod_with(customization, indices, &[0; CHECKSUM_LENGTH_WORDS]) ^ 1;
+    let mut checksum = [0u16; CHECKSUM_LENGTH_WORDS];
+    for (i, word) in checksum.iter_mut().enumerate() {
+        let shift = 10 * (CHECKSUM_LENGTH_WORDS - 1 - i);
+        *word = ((remainder >> shift) & 0x3FF) as u16;
+    }
+    checksum
+}
+
+/// Verify the checksum of a complete sequence of word indices
+///
+/// # Arguments
+/// * `indices` - Word indices (0-1023) of the mnemonic, including the three checksum words
+/// * `customization` - Customization string selected by the extendable flag
+///
+/// # Returns
+/// * `true` - If the last three words are a valid checksum of the preceding words
+pub fn verify_checksum(indices: &[u16], customization: Customization) -> bool {
+    indices.len() >= CHECKSUM_LENGTH_WORDS && residue(indices, customization) == 1
+}
+
+/// The 30-bit checksum residue of a complete mnemonic (1 when the checksum is valid)
+///
+/// The residue is an affine function of the word bits over GF(2), which is
+/// what makes erasure recovery a linear system.
+pub(crate) fn residue(indices: &[u16], customization: Customization) -> u32 {
+    polymod_with(customization, indices, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_then_verify() {
+        let mut indices: Vec<u16> = (0..17).map(|i| i * 60).collect();
+        let checksum = create_checksum(&indices, Customization::Shamir);
+        indices.extend_from_slice(&checksum);
+        assert!(verify_checksum(&indices, Customization::Shamir));
+        assert!(!verify_checksum(&indices, Customization::ShamirExtendable));
+    }
+
+    #[test]
+    fn test_single_word_change_is_detected() {
+        let mut indices: Vec<u16> = (0..17).map(|i| i * 37).collect();
+        let checksum = create_checksum(&indices, Customization::ShamirExtendable);
+        indices.extend_from_slice(&checksum);
+
+        for position in 0..indices.len() {
+            let mut corrupted = indices.clone();
+            corrupted[position] ^= 1;
+            assert!(!verify_checksum(
+                &corrupted,
+                Customization::ShamirExtendable
+            ));
+        }
+    }
+
+    #[test]
+    fn test_too_short_is_invalid() {
+        assert!(!verify_checksum(&[], Customization::Shamir));
+        assert!(!verify_checksum(&[1, 2], Customization::Shamir));
+    }
+}
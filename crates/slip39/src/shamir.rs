@@ -0,0 +1,682 @@
+//! Shamir secret sharing as specified by SLIP-39
+//!
+//! A secret is shared with a random polynomial over GF(256). Besides the share
+//! points, two special points are fixed: the secret itself at x = 255 and a
+//! digest share at x = 254, whose first four bytes authenticate the secret.
+
+use crate::cipher::EncryptedMasterSecret;
+use crate::gf256::interpolate;
+use crate::share::Share;
+use crate::Error;
+use hmac::{Hmac, Mac};
+use rand::{Rng, RngCore};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+
+/// Maximum number of shares in a group (member index is 4 bits)
+pub const MAX_SHARE_COUNT: u8 = 16;
+
+/// x-coordinate of the digest share
+pub const DIGEST_INDEX: u8 = 254;
+
+/// x-coordinate of the shared secret
+pub const SECRET_INDEX: u8 = 255;
+
+/// Length in bytes of the digest prefix of the digest share
+pub const DIGEST_LENGTH_BYTES: usize = 4;
+
+/// Iteration exponent used when none is specified
+pub const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+/// Minimum master secret length in bytes (128 bits)
+pub const MIN_SECRET_LENGTH_BYTES: usize = 16;
+
+/// Check that a master secret is at least 128 bits and a multiple of 16 bits
+pub(crate) fn validate_secret_length(secret: &[u8]) -> Result<(), Error> {
+    if secret.len() < MIN_SECRET_LENGTH_BYTES || !secret.len().is_multiple_of(2) {
+        return Err(Error::InvalidSecretLength(secret.len() * 8));
+    }
+    Ok(())
+}
+
+/// Compute the 4-byte digest of `secret` keyed by `random`
+fn create_digest(random: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH_BYTES] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random).expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+    digest.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LENGTH_BYTES]);
+    digest
+}
+
+/// Split `secret` into `count` points, any `threshold` of which recover it
+///
+/// # Returns
+/// * `Ok(Vec<(u8, Vec<u8>)>)` - `(x, value)` points with x = 0..count
+/// * `Err(Error::InvalidParameters)` - If the threshold or count are out of range
+pub(crate) fn split_secret(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+    rng: &mut impl RngCore,
+) -> Result<Vec<(u8, Vec<u8>)>, Error> {
+    if threshold < 1 {
+        return Err(Error::InvalidParameters(
+            "threshold must be at least 1".to_string(),
+        ));
+    }
+    if threshold > count {
+        return Err(Error::InvalidParameters(format!(
+            "threshold ({}) must not exceed the number of shares ({})",
+            threshold, count
+        )));
+    }
+    if count > MAX_SHARE_COUNT {
+        return Err(Error::InvalidParameters(format!(
+            "number of shares ({}) must not exceed {}",
+            count, MAX_SHARE_COUNT
+        )));
+    }
+
+    // With a threshold of 1 every share is the secret itself
+    if threshold == 1 {
+        return Ok((0..count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let random_length = secret.len() - DIGEST_LENGTH_BYTES;
+    let mut random_part = vec![0u8; random_length];
+    rng.fill_bytes(&mut random_part);
+
+    let mut digest_share = create_digest(&random_part, secret).to_vec();
+    digest_share.extend_from_slice(&random_part);
+
+    // The first threshold - 2 shares are random; together with the digest and
+    // secret points they fix the polynomial, from which the rest are derived.
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..threshold - 2)
+        .map(|i| {
+            let mut value = vec![0u8; secret.len()];
+            rng.fill_bytes(&mut value);
+            (i, value)
+        })
+        .collect();
+
+    let mut base: Vec<(u8, &[u8])> = shares.iter().map(|(x, v)| (*x, v.as_slice())).collect();
+    base.push((DIGEST_INDEX, &digest_share));
+    base.push((SECRET_INDEX, secret));
+
+    let derived = (threshold - 2..count)
+        .map(|i| interpolate(&base, i).map(|value| (i, value)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    shares.extend(derived);
+
+    Ok(shares)
+}
+
+/// Recover a secret from `threshold` points and verify its digest
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The shared secret
+/// * `Err(Error::InvalidDigest)` - If the digest share does not authenticate the secret
+/// * `Err(Error::InconsistentShares)` - If the points cannot be interpolated
+pub(crate) fn recover_secret(threshold: u8, points: &[(u8, &[u8])]) -> Result<Vec<u8>, Error> {
+    // With a threshold of 1 every share is the secret itself
+    if threshold == 1 {
+        return points
+            .first()
+            .map(|(_, value)| value.to_vec())
+            .ok_or_else(|| {
+                Error::InsufficientShares("at least one share is required".to_string())
+            });
+    }
+
+    let secret = interpolate(points, SECRET_INDEX)?;
+    let digest_share = interpolate(points, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+
+    // Compare without an early exit so the position of a mismatch is not revealed
+    let difference = create_digest(random_part, &secret)
+        .iter()
+        .zip(digest)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    if difference != 0 {
+        return Err(Error::InvalidDigest);
+    }
+
+    Ok(secret)
+}
+
+/// Recover a secret from surplus points, checking every `threshold`-sized subset
+///
+/// With exactly `threshold` points this is [`recover_secret`]. With more, every
+/// subset is recovered: subsets containing a corrupted point fail the digest
+/// check, so the points that belong to no subset recovering the authenticated
+/// secret are the inconsistent ones.
+///
+/// # Arguments
+/// * `threshold` - Number of points required to recover the secret
+/// * `points` - `(x, value)` points with distinct x-coordinates
+/// * `describe` - Names a point by its x-coordinate in error messages
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The secret recovered by every consistent subset
+/// * `Err(Error::InconsistentShares)` - Naming the points that disagree with the others
+/// * `Err(Error::InvalidDigest)` - If no subset recovers an authenticated secret
+fn recover_secret_checked(
+    threshold: u8,
+    points: &[(u8, &[u8])],
+    describe: impl Fn(u8) -> String,
+) -> Result<Vec<u8>, Error> {
+    if points.len() <= usize::from(threshold) {
+        return recover_secret(threshold, points);
+    }
+
+    // Every distinct authenticated secret, with the x-coordinates that recovered it
+    let mut recovered: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+    for subset in combinations(points.len(), usize::from(threshold)) {
+        let selected: Vec<(u8, &[u8])> = subset.iter().map(|&i| points[i]).collect();
+        let Ok(secret) = recover_secret(threshold, &selected) else {
+            continue;
+        };
+        let xs = selected.iter().map(|&(x, _)| x);
+        match recovered.iter_mut().find(|(other, _)| *other == secret) {
+            Some((_, support)) => support.extend(xs),
+            None => recovered.push((secret, xs.collect())),
+        }
+    }
+
+    match recovered.as_slice() {
+        [] => Err(Error::InvalidDigest),
+        [(secret, support)] => {
+            let outliers: Vec<String> = points
+                .iter()
+                .filter(|(x, _)| !support.contains(x))
+                .map(|&(x, _)| describe(x))
+                .collect();
+            match outliers.len() {
+                0 => Ok(secret.clone()),
+                1 => Err(Error::InconsistentShares(format!(
+                    "{} does not match the secret recovered from the other shares",
+                    outliers[0]
+                ))),
+                _ => Err(Error::InconsistentShares(format!(
+                    "{} do not match the secret recovered from the other shares",
+                    outliers.join(", ")
+                ))),
+            }
+        }
+        _ => Err(Error::InconsistentShares(format!(
+            "subsets of {} recover different secrets",
+            points
+                .iter()
+                .map(|&(x, _)| describe(x))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+/// All `k`-element subsets of `0..n` as increasing index lists (`1 <= k <= n`)
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut subsets = Vec::new();
+    let mut current: Vec<usize> = (0..k).collect();
+    loop {
+        subsets.push(current.clone());
+        // Advance the rightmost index that has not reached its final value
+        let Some(i) = (0..k).rev().find(|&i| current[i] < n - k + i) else {
+            return subsets;
+        };
+        current[i] += 1;
+        for j in i + 1..k {
+            current[j] = current[j - 1] + 1;
+        }
+    }
+}
+
+/// Split a master secret into a single group of SLIP-39 shares
+///
+/// Shorthand for [`split_groups`] with one group, a group threshold of 1, the
+/// default iteration exponent and an extendable backup.
+///
+/// # Arguments
+/// * `master_secret` - The secret to share (at least 128 bits, multiple of 16 bits)
+/// * `passphrase` - Printable ASCII passphrase used to encrypt the secret (may be empty)
+/// * `threshold` - Number of shares required to recover the secret (1-16)
+/// * `count` - Total number of shares to create (1-16)
+///
+/// # Returns
+/// * `Ok(Vec<Share>)` - `count` shares with member indices 0..count
+/// * `Err(Error)` - See [`split_groups`]
+pub fn split(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    threshold: u8,
+    count: u8,
+) -> Result<Vec<Share>, Error> {
+    let mut groups = split_groups(
+        master_secret,
+        passphrase,
+        true,
+        DEFAULT_ITERATION_EXPONENT,
+        1,
+        &[(threshold, count)],
+    )?;
+    Ok(groups.remove(0))
+}
+
+/// Split a master secret into groups of SLIP-39 shares (two-level scheme)
+///
+/// The secret is encrypted with the passphrase, then split into one share per
+/// group, any `group_threshold` of which recover it; each group share is split
+/// again among the group members. Randomness comes from [`rand::thread_rng`],
+/// the same CSPRNG as [`crate::get_random_word`].
+///
+/// # Arguments
+/// * `master_secret` - The secret to share (at least 128 bits, multiple of 16 bits)
+/// * `passphrase` - Printable ASCII passphrase used to encrypt the secret (may be empty)
+/// * `extendable` - Create an extendable backup, so that further share sets can be
+///   issued later for the same encrypted master secret
+/// * `iteration_exponent` - PBKDF2 cost exponent of the encryption (0-15)
+/// * `group_threshold` - Number of groups required to recover the secret
+/// * `groups` - `(member_threshold, member_count)` for each group (at most 16 groups)
+///
+/// # Returns
+/// * `Ok(Vec<Vec<Share>>)` - The shares of every group, in group order
+/// * `Err(Error::InvalidSecretLength)` - If the secret length is not allowed
+/// * `Err(Error::InvalidPassphrase)` - If the passphrase is not printable ASCII
+/// * `Err(Error::InvalidParameters)` - If the group layout violates SLIP-39 constraints
+pub fn split_groups(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    extendable: bool,
+    iteration_exponent: u8,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error> {
+    let ems = EncryptedMasterSecret::from_master_secret(
+        master_secret,
+        passphrase,
+        random_identifier(),
+        extendable,
+        iteration_exponent,
+    )?;
+    split_ems(&ems, group_threshold, groups)
+}
+
+/// Random 15-bit share set identifier
+fn random_identifier() -> u16 {
+    rand::thread_rng().gen::<u16>() & 0x7FFF
+}
+
+/// Split an already encrypted master secret into groups of SLIP-39 shares
+///
+/// The identifier, extendable flag and iteration exponent of the shares are
+/// taken from `ems`.
+///
+/// # Arguments
+/// * `ems` - The encrypted master secret
+/// * `group_threshold` - Number of groups required to recover the secret
+/// * `groups` - `(member_threshold, member_count)` for each group (at most 16 groups)
+///
+/// # Returns
+/// * `Ok(Vec<Vec<Share>>)` - The shares of every group, in group order
+/// * `Err(Error)` - See [`split_groups`]
+pub fn split_ems(
+    ems: &EncryptedMasterSecret,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+) -> Result<Vec<Vec<Share>>, Error> {
+    validate_secret_length(&ems.ciphertext)?;
+
+    if groups.is_empty() || groups.len() > usize::from(MAX_SHARE_COUNT) {
+        return Err(Error::InvalidParameters(format!(
+            "number of groups ({}) must be between 1 and {}",
+            groups.len(),
+            MAX_SHARE_COUNT
+        )));
+    }
+    if group_threshold as usize > groups.len() {
+        return Err(Error::InvalidParameters(format!(
+            "group threshold ({}) must not exceed the number of groups ({})",
+            group_threshold,
+            groups.len()
+        )));
+    }
+    if groups
+        .iter()
+        .any(|&(threshold, count)| threshold == 1 && count > 1)
+    {
+        return Err(Error::InvalidParameters(
+            "creating multiple member shares with member threshold 1 is not allowed, \
+             use 1-of-1 member sharing instead"
+                .to_string(),
+        ));
+    }
+
+    let mut rng = rand::thread_rng();
+    let group_shares = split_secret(
+        group_threshold,
+        groups.len() as u8,
+        &ems.ciphertext,
+        &mut rng,
+    )?;
+
+    group_shares
+        .into_iter()
+        .zip(groups)
+        .map(
+            |((group_index, group_secret), &(member_threshold, count))| {
+                let members = split_secret(member_threshold, count, &group_secret, &mut rng)?;
+                Ok(members
+                    .into_iter()
+                    .map(|(member_index, value)| Share {
+                        identifier: ems.identifier,
+                        extendable: ems.extendable,
+                        iteration_exponent: ems.iteration_exponent,
+                        group_index,
+                        group_threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold,
+                        value,
+                    })
+                    .collect())
+            },
+        )
+        .collect()
+}
+
+/// Issue a new share set for the secret of existing shares, without decrypting it
+///
+/// The encrypted master secret is recovered from the shares and split again
+/// with a new group layout; no passphrase is needed. Extendable backups get a
+/// fresh identifier: their encryption does not depend on it, so the new set
+/// restores the same wallet and cannot be mixed up with the old one.
+///
+/// Non-extendable backups must keep their identifier, which is part of the
+/// encryption salt. Shares of both sets then look like a single set: a mix of
+/// old and new shares is reported as recoverable by [`crate::share_status`], but
+/// fails to combine. Such backups are only reshared when `reuse_identifier` is set.
+///
+/// Resharing does not invalidate the old shares, which still recover the secret.
+///
+/// # Arguments
+/// * `shares` - Enough shares of a single share set to recover its secret
+/// * `group_threshold` - Number of groups required to recover the secret
+/// * `groups` - `(member_threshold, member_count)` for each group (at most 16 groups)
+/// * `reuse_identifier` - Allow resharing a non-extendable backup under its identifier
+///
+/// # Returns
+/// * `Ok(Vec<Vec<Share>>)` - The shares of every new group, in group order
+/// * `Err(Error::InvalidParameters)` - If the backup is not extendable and `reuse_identifier` is not set
+/// * `Err(Error)` - See [`recover_ems`] and [`split_ems`]
+pub fn reshare(
+    shares: &[Share],
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    reuse_identifier: bool,
+) -> Result<Vec<Vec<Share>>, Error> {
+    let mut ems = recover_ems(shares)?;
+    if !ems.extendable && !reuse_identifier {
+        return Err(Error::InvalidParameters(format!(
+            "share set {} is not extendable: new shares would keep its identifier and \
+             could not be told apart from the old ones; reusing the identifier must be allowed explicitly",
+            ems.identifier
+        )));
+    }
+    if ems.extendable {
+        let previous = ems.identifier;
+        while ems.identifier == previous {
+            ems.identifier = random_identifier();
+        }
+    }
+    split_ems(&ems, group_threshold, groups)
+}
+
+/// Recover the master secret from a set of shares and decrypt it
+///
+/// # Arguments
+/// * `shares` - Shares of a single share set, in any order
+/// * `passphrase` - The passphrase used when the shares were created (may be empty)
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The recovered master secret
+/// * `Err(Error::InvalidPassphrase)` - If the passphrase is not printable ASCII
+/// * `Err(Error)` - See [`recover_ems`]
+pub fn combine(shares: &[Share], passphrase: &[u8]) -> Result<Vec<u8>, Error> {
+    recover_ems(shares)?.decrypt(passphrase)
+}
+
+/// Recover the encrypted master secret from a set of shares
+///
+/// Shares are grouped by group index. Every group needs at least its member
+/// threshold of shares and at least `group_threshold` groups are required.
+/// Identical duplicate shares are ignored. Surplus shares and groups are used
+/// as a cross-check: every threshold-sized subset must recover the same
+/// secret, and a share that disagrees with the others is reported.
+///
+/// # Arguments
+/// * `shares` - Shares of a single share set, in any order
+///
+/// # Returns
+/// * `Ok(EncryptedMasterSecret)` - The encrypted master secret and its parameters
+/// * `Err(Error::InsufficientShares)` - If not enough groups or members are present
+/// * `Err(Error::InconsistentShares)` - If the shares do not belong to the same set, or naming
+///   the surplus shares that do not match the secret recovered from the others
+/// * `Err(Error::InvalidShare)` - If a group index is outside the groups of the set
+/// * `Err(Error::InvalidDigest)` - If a recovered secret fails digest verification
+pub fn recover_ems(shares: &[Share]) -> Result<EncryptedMasterSecret, Error> {
+    let Some(first) = shares.first() else {
+        return Err(Error::InsufficientShares(
+            "the list of shares is empty".to_string(),
+        ));
+    };
+
+    for share in shares {
+        if share.identifier != first.identifier
+            || share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+        {
+            return Err(Error::InconsistentShares(
+                "all shares must have the same identifier, extendable flag and iteration exponent"
+                    .to_string(),
+            ));
+        }
+        if share.group_threshold != first.group_threshold || share.group_count != first.group_count
+        {
+            return Err(Error::InconsistentShares(
+                "all shares must have the same group threshold and group count".to_string(),
+            ));
+        }
+        if share.group_index >= share.group_count {
+            return Err(Error::InvalidShare(format!(
+                "group index ({}) must be less than group count ({})",
+                share.group_index, share.group_count
+            )));
+        }
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in shares {
+        let members = groups.entry(share.group_index).or_default();
+        if let Some(other) = members
+            .iter()
+            .find(|other| other.member_index == share.member_index)
+        {
+            if other.value != share.value {
+                return Err(Error::InconsistentShares(format!(
+                    "group {} contains different shares with member index {}",
+                    share.group_index, share.member_index
+                )));
+            }
+            continue;
+        }
+        if members
+            .first()
+            .is_some_and(|other| other.member_threshold != share.member_threshold)
+        {
+            return Err(Error::InconsistentShares(format!(
+                "shares of group {} have different member thresholds",
+                share.group_index
+            )));
+        }
+        members.push(share);
+    }
+
+    let complete: Vec<(u8, &Vec<&Share>)> = groups
+        .iter()
+        .filter(|(_, members)| members.len() >= usize::from(members[0].member_threshold))
+        .map(|(&index, members)| (index, members))
+        .collect();
+
+    if complete.len() < usize::from(first.group_threshold) {
+        let missing = groups
+            .iter()
+            .filter(|(_, members)| members.len() < usize::from(members[0].member_threshold))
+            .map(|(index, members)| {
+                format!(
+                    "group {} has {} of {} shares",
+                    index,
+                    members.len(),
+                    members[0].member_threshold
+                )
+            })
+            .collect::<Vec<_>>();
+        let detail = if missing.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", missing.join(", "))
+        };
+        return Err(Error::InsufficientShares(format!(
+            "{} of {} required groups are complete{}",
+            complete.len(),
+            first.group_threshold,
+            detail
+        )));
+    }
+
+    let group_secrets = complete
+        .iter()
+        .map(|(group_index, members)| {
+            let points: Vec<(u8, &[u8])> = members
+                .iter()
+                .map(|share| (share.member_index, share.value.as_slice()))
+                .collect();
+            let describe = |member_index| {
+                format!(
+                    "share with member index {} in group {}",
+                    member_index, group_index
+                )
+            };
+            recover_secret_checked(members[0].member_threshold, &points, describe)
+                .map(|secret| (*group_index, secret))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let points: Vec<(u8, &[u8])> = group_secrets
+        .iter()
+        .map(|(index, secret)| (*index, secret.as_slice()))
+        .collect();
+    let ciphertext = recover_secret_checked(first.group_threshold, &points, |group_index| {
+        format!("group {}", group_index)
+    })?;
+    Ok(EncryptedMasterSecret {
+        identifier: first.identifier,
+        extendable: first.extendable,
+        iteration_exponent: first.iteration_exponent,
+        ciphertext,
+    })
+}
+
+/// Recover the master secret from a set of mnemonics
+///
+/// # Arguments
+/// * `mnemonics` - Share mnemonics of a single share set, in any order
+/// * `passphrase` - The passphrase used when the shares were created (may be empty)
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The recovered master secret
+/// * `Err(Error)` - If a mnemonic cannot be parsed, or see [`combine`]
+///
+/// # Example
+/// ```
+/// use slip39_calculator::combine_mnemonics;
+///
+/// let share = "duckling enlarge academic academic agency result length solution fridge \
+///              kidney coal piece deal husband erode duke ajar critical decision keyboard";
+/// let secret = combine_mnemonics(&[share], b"TREZOR").unwrap();
+/// assert_eq!(hex::encode(secret), "bb54aac4b89dc868ba37d9cc21b2cece");
+/// ```
+pub fn combine_mnemonics<S: AsRef<str>>(
+    mnemonics: &[S],
+    passphrase: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let shares = mnemonics
+        .iter()
+        .map(|mnemonic| Share::from_mnemonic(mnemonic.as_ref()))
+        .collect::<Result<Vec<_>, Error>>()?;
+    combine(&shares, passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 16] = *b"ABCDEFGHIJKLMNOP";
+
+    #[test]
+    fn test_any_threshold_subset_recovers_secret() {
+        let points = split_secret(3, 5, &SECRET, &mut rand::thread_rng()).unwrap();
+        assert_eq!(points.len(), 5);
+
+        for subset in [[0, 1, 2], [0, 2, 4], [1, 3, 4], [2, 3, 4]] {
+            let selected: Vec<(u8, &[u8])> = subset
+                .iter()
+                .map(|&i| (points[i].0, points[i].1.as_slice()))
+                .collect();
+            assert_eq!(interpolate(&selected, SECRET_INDEX).unwrap(), SECRET);
+
+            let digest_share = interpolate(&selected, DIGEST_INDEX).unwrap();
+            let (digest, random) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+            assert_eq!(digest, create_digest(random, &SECRET));
+        }
+    }
+
+    #[test]
+    fn test_combinations() {
+        assert_eq!(combinations(3, 3), vec![vec![0, 1, 2]]);
+        assert_eq!(
+            combinations(4, 2),
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+        assert_eq!(combinations(16, 8).len(), 12870);
+    }
+
+    #[test]
+    fn test_threshold_one_copies_secret() {
+        let points = split_secret(1, 3, &SECRET, &mut rand::thread_rng()).unwrap();
+        assert!(points.iter().all(|(_, value)| value == &SECRET));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let mut rng = rand::thread_rng();
+        assert!(matches!(
+            split_secret(0, 3, &SECRET, &mut rng),
+            Err(Error::InvalidParameters(_))
+        ));
+        assert!(matches!(
+            split_secret(4, 3, &SECRET, &mut rng),
+            Err(Error::InvalidParameters(_))
+        ));
+        assert!(matches!(
+            split_secret(2, 17, &SECRET, &mut rng),
+            Err(Error::InvalidParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,389 @@
+//! SLIP-39 share parsing and serialization
+//!
+//! A share mnemonic is laid out as follows (sizes in bits):
+//!
+//! | Field              | Bits | Notes                           |
+//! |--------------------|------|---------------------------------|
+//! | identifier         | 15   | random, shared by the whole set |
+//! | extendable         | 1    | extendable backup flag          |
+//! | iteration exponent | 4    | PBKDF2 cost                     |
+//! | group index        | 4    |                                 |
+//! | group threshold    | 4    | stored as `threshold - 1`       |
+//! | group count        | 4    | stored as `count - 1`           |
+//! | member index       | 4    |                                 |
+//! | member threshold   | 4    | stored as `threshold - 1`       |
+//! | padded share value | 10n  | left-padded with zero bits      |
+//! | checksum           | 30   | RS1024                          |
+
+use crate::packing::{bytes_to_indices, indices_to_bytes, RADIX_BITS};
+use crate::rs1024::{create_checksum, verify_checksum, Customization, CHECKSUM_LENGTH_WORDS};
+use crate::shamir::MIN_SECRET_LENGTH_BYTES;
+use crate::word_index::WordIndex;
+use crate::{wordlist, Error};
+
+/// Number of words used by the share header (40 bits)
+pub const HEADER_LENGTH_WORDS: usize = 4;
+
+/// Minimum length of a mnemonic: a 128-bit share value needs 13 words
+pub const MIN_MNEMONIC_LENGTH_WORDS: usize = HEADER_LENGTH_WORDS + 13 + CHECKSUM_LENGTH_WORDS;
+
+/// Names and sizes in bits of the header fields, in order
+const HEADER_FIELDS: [(&str, usize); 8] = [
+    ("identifier", 15),
+    ("extendable", 1),
+    ("iteration exponent", 4),
+    ("group index", 4),
+    ("group threshold", 4),
+    ("group count", 4),
+    ("member index", 4),
+    ("member threshold", 4),
+];
+
+/// A field of the share layout, located in the bit string of the mnemonic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    /// Field name, as in the table of the module documentation
+    pub name: &'static str,
+    /// Position of the first bit, counting from the first bit of the first word
+    pub offset: usize,
+    /// Size of the field in bits
+    pub bits: usize,
+}
+
+impl Field {
+    /// Indices of the first and last word holding bits of this field
+    pub fn word_span(&self) -> (usize, usize) {
+        (
+            self.offset / RADIX_BITS,
+            (self.offset + self.bits - 1) / RADIX_BITS,
+        )
+    }
+
+    /// Read the field from word indices as a number (fields of at most 64 bits)
+    pub fn read(&self, indices: &[u16]) -> u64 {
+        (self.offset..self.offset + self.bits).fold(0, |acc, i| (acc << 1) | bit(indices, i))
+    }
+
+    /// Read the field from word indices as big-endian bytes
+    ///
+    /// The first byte holds the leading bits when the size is not a multiple of 8.
+    pub fn read_bytes(&self, indices: &[u16]) -> Vec<u8> {
+        let leading = self.bits % 8;
+        let mut bytes = Vec::with_capacity(self.bits.div_ceil(8));
+        let mut start = self.offset;
+        for len in std::iter::once(leading)
+            .filter(|&len| len > 0)
+            .chain(std::iter::repeat_n(8, self.bits / 8))
+        {
+            bytes.push((start..start + len).fold(0u8, |acc, i| (acc << 1) | bit(indices, i) as u8));
+            start += len;
+        }
+        bytes
+    }
+}
+
+/// Layout of a share mnemonic with the given number of words
+///
+/// Returns the header fields, the padding, the share value and the checksum in order.
+///
+/// # Arguments
+/// * `word_count` - Number of words in the mnemonic, including the checksum
+///
+/// # Returns
+/// * `Ok(Vec<Field>)` - The fields, covering every bit of the mnemonic
+/// * `Err(Error::InvalidMnemonicLength)` - If the word count cannot hold a valid share
+pub fn layout(word_count: usize) -> Result<Vec<Field>, Error> {
+    if word_count < MIN_MNEMONIC_LENGTH_WORDS {
+        return Err(Error::InvalidMnemonicLength(word_count));
+    }
+    let value_words = word_count - HEADER_LENGTH_WORDS - CHECKSUM_LENGTH_WORDS;
+    let padding_bits = (RADIX_BITS * value_words) % 16;
+    if padding_bits > 8 {
+        return Err(Error::InvalidMnemonicLength(word_count));
+    }
+
+    let sizes = HEADER_FIELDS.into_iter().chain([
+        ("padding", padding_bits),
+        ("share value", RADIX_BITS * value_words - padding_bits),
+        ("checksum", RADIX_BITS * CHECKSUM_LENGTH_WORDS),
+    ]);
+    let mut offset = 0;
+    Ok(sizes
+        .filter(|&(_, bits)| bits > 0)
+        .map(|(name, bits)| {
+            let field = Field { name, offset, bits };
+            offset += bits;
+            field
+        })
+        .collect())
+}
+
+/// Bit `i` of the concatenated 10-bit words, most significant first
+fn bit(indices: &[u16], i: usize) -> u64 {
+    u64::from(indices[i / RADIX_BITS] >> (RADIX_BITS - 1 - i % RADIX_BITS)) & 1
+}
+
+/// A single SLIP-39 share with every header field decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    /// Random identifier common to all shares of a set (15 bits)
+    pub identifier: u16,
+    /// Extendable backup flag
+    pub extendable: bool,
+    /// Iteration exponent used by the passphrase encryption (0-15)
+    pub iteration_exponent: u8,
+    /// Index of the group this share belongs to (0-15)
+    pub group_index: u8,
+    /// Number of groups required to recover the secret (1-16)
+    pub group_threshold: u8,
+    /// Total number of groups (1-16)
+    pub group_count: u8,
+    /// Index of this share within its group (0-15)
+    pub member_index: u8,
+    /// Number of members required to recover the group secret (1-16)
+    pub member_threshold: u8,
+    /// Share value, without the zero padding
+    pub value: Vec<u8>,
+}
+
+impl Share {
+    /// Parse a share from a whitespace-separated mnemonic
+    ///
+    /// Words may be given as four-letter abbreviations or other unique prefixes,
+    /// and are looked up in constant time (see [`WordIndex::from_prefix_ct`]).
+    ///
+    /// # Arguments
+    /// * `mnemonic` - The share mnemonic (20 words or more)
+    ///
+    /// # Returns
+    /// * `Ok(Share)` - The decoded share
+    /// * `Err(Error::WordNotFound)` / `Err(Error::AmbiguousPrefix)` - If a word cannot be resolved
+    /// * `Err(Error::InvalidMnemonicLength)` - If the word count cannot hold a valid share
+    /// * `Err(Error::InvalidChecksum)` - If the RS1024 checksum does not match
+    /// * `Err(Error::InvalidPadding)` - If the share value padding bits are not zero
+    /// * `Err(Error::InvalidShare)` - If the header fields are inconsistent
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self, Error> {
+        let indices = mnemonic
+            .split_whitespace()
+            .map(|word| WordIndex::from_prefix_ct(word).map(WordIndex::value))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Self::from_indices(&indices)
+    }
+
+    /// Parse a share from its word indices
+    ///
+    /// # Arguments
+    /// * `indices` - Word indices (0-1023), including the checksum words
+    ///
+    /// # Returns
+    /// * `Ok(Share)` - The decoded share
+    /// * `Err(Error::IndexOutOfRange)` - If an index is > 1023
+    /// * `Err(Error)` - See [`Share::from_mnemonic`]
+    pub fn from_indices(indices: &[u16]) -> Result<Self, Error> {
+        if let Some(&index) = indices.iter().find(|&&i| i >= WordIndex::COUNT) {
+            return Err(Error::IndexOutOfRange(usize::from(index)));
+        }
+        if indices.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(Error::InvalidMnemonicLength(indices.len()));
+        }
+
+        let value_words = indices.len() - HEADER_LENGTH_WORDS - CHECKSUM_LENGTH_WORDS;
+        let padding_bits = (RADIX_BITS * value_words) % 16;
+        if padding_bits > 8 {
+            return Err(Error::InvalidMnemonicLength(indices.len()));
+        }
+
+        let header = indices[..HEADER_LENGTH_WORDS]
+            .iter()
+            .fold(0u64, |acc, &w| (acc << RADIX_BITS) | u64::from(w));
+        let field = |shift: u32, bits: u32| ((header >> shift) & ((1 << bits) - 1)) as u8;
+
+        let extendable = field(24, 1) == 1;
+        if !verify_checksum(indices, Customization::from_extendable(extendable)) {
+            return Err(Error::InvalidChecksum);
+        }
+
+        let share = Share {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: field(20, 4),
+            group_index: field(16, 4),
+            group_threshold: field(12, 4) + 1,
+            group_count: field(8, 4) + 1,
+            member_index: field(4, 4),
+            member_threshold: field(0, 4) + 1,
+            value: indices_to_bytes(
+                &indices[HEADER_LENGTH_WORDS..indices.len() - CHECKSUM_LENGTH_WORDS],
+                (RADIX_BITS * value_words - padding_bits) / 8,
+            )?,
+        };
+
+        if share.group_threshold > share.group_count {
+            return Err(Error::InvalidShare(format!(
+                "group threshold ({}) cannot be greater than group count ({})",
+                share.group_threshold, share.group_count
+            )));
+        }
+        if share.group_index >= share.group_count {
+            return Err(Error::InvalidShare(format!(
+                "group index ({}) must be less than group count ({})",
+                share.group_index, share.group_count
+            )));
+        }
+
+        Ok(share)
+    }
+
+    /// Pack the header fields into 40 bits, checking that each one fits
+    fn header(&self) -> Result<u64, Error> {
+        let field = |name: &str, value: u16, min: u16, max: u16| {
+            if (min..=max).contains(&value) {
+                Ok(u64::from(value - min))
+            } else {
+                Err(Error::InvalidShare(format!(
+                    "{} ({}) must be between {} and {}",
+                    name, value, min, max
+                )))
+            }
+        };
+        let header = (field("identifier", self.identifier, 0, 0x7FFF)? << 25)
+            | (u64::from(self.extendable) << 24)
+            | (field("iteration exponent", self.iteration_exponent.into(), 0, 15)? << 20)
+            | (field("group index", self.group_index.into(), 0, 15)? << 16)
+            | (field("group threshold", self.group_threshold.into(), 1, 16)? << 12)
+            | (field("group count", self.group_count.into(), 1, 16)? << 8)
+            | (field("member index", self.member_index.into(), 0, 15)? << 4)
+            | field("member threshold", self.member_threshold.into(), 1, 16)?;
+
+        if self.group_threshold > self.group_count {
+            return Err(Error::InvalidShare(format!(
+                "group threshold ({}) cannot be greater than group count ({})",
+                self.group_threshold, self.group_count
+            )));
+        }
+        if self.group_index >= self.group_count {
+            return Err(Error::InvalidShare(format!(
+                "group index ({}) must be less than group count ({})",
+                self.group_index, self.group_count
+            )));
+        }
+        Ok(header)
+    }
+
+    /// Serialize the share to word indices, including the checksum
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u16>)` - The word indices (0-1023)
+    /// * `Err(Error::InvalidShare)` - If a header field does not fit its bits, the
+    ///   group threshold is greater than the group count, or the value is shorter
+    ///   than 16 bytes or has an odd length
+    pub fn to_indices(&self) -> Result<Vec<u16>, Error> {
+        let header = self.header()?;
+        // Other lengths do not serialize to a word count that can be parsed back
+        if self.value.len() < MIN_SECRET_LENGTH_BYTES || !self.value.len().is_multiple_of(2) {
+            return Err(Error::InvalidShare(format!(
+                "share value must be an even number of bytes, at least {}, got {} bytes",
+                MIN_SECRET_LENGTH_BYTES,
+                self.value.len()
+            )));
+        }
+        let mut indices: Vec<u16> = (0..HEADER_LENGTH_WORDS)
+            .rev()
+            .map(|i| ((header >> (RADIX_BITS * i)) & 0x3FF) as u16)
+            .collect();
+        indices.extend(bytes_to_indices(&self.value));
+        let checksum = create_checksum(&indices, Customization::from_extendable(self.extendable));
+        indices.extend(checksum);
+        Ok(indices)
+    }
+
+    /// Serialize the share to a space-separated mnemonic
+    ///
+    /// # Returns
+    /// See [`Share::to_indices`]
+    pub fn to_mnemonic(&self) -> Result<String, Error> {
+        self.to_words().map(|words| words.join(" "))
+    }
+
+    /// Serialize the share to its list of words
+    ///
+    /// # Returns
+    /// See [`Share::to_indices`]
+    pub fn to_words(&self) -> Result<Vec<&'static str>, Error> {
+        Ok(self
+            .to_indices()?
+            .into_iter()
+            .map(|i| wordlist()[usize::from(i)])
+            .collect())
+    }
+
+    /// Serialize the share to space-separated four-letter abbreviations
+    ///
+    /// The abbreviations are accepted back by [`Share::from_mnemonic`].
+    ///
+    /// # Returns
+    /// See [`Share::to_indices`]
+    pub fn to_abbreviations(&self) -> Result<String, Error> {
+        self.to_indices()?
+            .into_iter()
+            .map(|i| WordIndex::new(i).map(WordIndex::abbreviation))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|abbreviations| abbreviations.join(" "))
+    }
+
+    /// The three checksum word indices of this share
+    ///
+    /// # Returns
+    /// See [`Share::to_indices`]
+    pub fn checksum(&self) -> Result<[u16; 3], Error> {
+        let indices = self.to_indices()?;
+        let mut checksum = [0u16; CHECKSUM_LENGTH_WORDS];
+        checksum.copy_from_slice(&indices[indices.len() - CHECKSUM_LENGTH_WORDS..]);
+        Ok(checksum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_matches_share_fields() {
+        let share = Share {
+            identifier: 7945,
+            extendable: true,
+            iteration_exponent: 3,
+            group_index: 2,
+            group_threshold: 2,
+            group_count: 4,
+            member_index: 5,
+            member_threshold: 3,
+            value: (0..16).map(|i| i * 13).collect(),
+        };
+        let indices = share.to_indices().unwrap();
+        let fields = layout(indices.len()).unwrap();
+        let read = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap()
+                .read(&indices)
+        };
+
+        assert_eq!(read("identifier"), 7945);
+        assert_eq!(read("extendable"), 1);
+        assert_eq!(read("iteration exponent"), 3);
+        assert_eq!(read("group index"), 2);
+        assert_eq!(read("group threshold"), 1);
+        assert_eq!(read("group count"), 3);
+        assert_eq!(read("member index"), 5);
+        assert_eq!(read("member threshold"), 2);
+        assert_eq!(read("padding"), 0);
+
+        let value = fields.iter().find(|f| f.name == "share value").unwrap();
+        assert_eq!(value.read_bytes(&indices), share.value);
+        let last = fields.last().unwrap();
+        assert_eq!(last.offset + last.bits, indices.len() * RADIX_BITS);
+        assert_eq!(last.word_span(), (indices.len() - 3, indices.len() - 1));
+    }
+}
@@ -0,0 +1,175 @@
+//! Recovery progress of a collection of shares
+//!
+//! Shares are sorted into share sets by identifier and checked against the
+//! thresholds in their headers. Only header fields are compared (and share
+//! values for duplicates), so the secret is never reconstructed.
+
+use crate::share::Share;
+use std::collections::BTreeMap;
+
+/// Progress of a single group within a share set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupStatus {
+    /// Index of the group (0-15)
+    pub group_index: u8,
+    /// Number of members required to recover the group secret
+    pub member_threshold: u8,
+    /// Distinct member indices present, in increasing order
+    pub member_indices: Vec<u8>,
+}
+
+impl GroupStatus {
+    /// Whether enough members are present to recover the group secret
+    pub fn is_complete(&self) -> bool {
+        self.members_needed() == 0
+    }
+
+    /// Number of additional members needed to recover the group secret
+    pub fn members_needed(&self) -> usize {
+        usize::from(self.member_threshold).saturating_sub(self.member_indices.len())
+    }
+}
+
+/// Progress of a share set (all shares with the same identifier)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetStatus {
+    /// Identifier of the share set
+    pub identifier: u16,
+    /// Extendable backup flag
+    pub extendable: bool,
+    /// Iteration exponent of the passphrase encryption
+    pub iteration_exponent: u8,
+    /// Number of groups required to recover the secret
+    pub group_threshold: u8,
+    /// Total number of groups
+    pub group_count: u8,
+    /// Groups with at least one share, in increasing group index
+    pub groups: Vec<GroupStatus>,
+    /// Shares that contradict the rest of the set; they are not counted
+    pub conflicts: Vec<String>,
+}
+
+impl SetStatus {
+    /// Number of groups with enough members
+    pub fn complete_groups(&self) -> usize {
+        self.groups.iter().filter(|g| g.is_complete()).count()
+    }
+
+    /// Number of additional complete groups needed to recover the secret
+    pub fn groups_needed(&self) -> usize {
+        usize::from(self.group_threshold).saturating_sub(self.complete_groups())
+    }
+
+    /// Indices of the groups for which no share is present
+    pub fn missing_groups(&self) -> Vec<u8> {
+        (0..self.group_count)
+            .filter(|&index| !self.groups.iter().any(|g| g.group_index == index))
+            .collect()
+    }
+
+    /// Whether the thresholds are met and no conflicting shares were found
+    pub fn is_recoverable(&self) -> bool {
+        self.groups_needed() == 0 && self.conflicts.is_empty()
+    }
+}
+
+/// Report the recovery progress of any collection of shares
+///
+/// Identical duplicates are ignored. A share whose header disagrees with the
+/// first share of its set, or which reuses a member index with a different
+/// value, is listed as a conflict instead of being counted.
+///
+/// # Arguments
+/// * `shares` - Shares of one or more share sets, in any order
+///
+/// # Returns
+/// * `Vec<SetStatus>` - One entry per identifier, in increasing identifier order
+pub fn share_status(shares: &[Share]) -> Vec<SetStatus> {
+    let mut sets: BTreeMap<u16, Vec<&Share>> = BTreeMap::new();
+    for share in shares {
+        sets.entry(share.identifier).or_default().push(share);
+    }
+
+    sets.into_values()
+        .map(|shares| set_status(&shares))
+        .collect()
+}
+
+/// Status of the shares of a single identifier (`shares` is non-empty)
+fn set_status(shares: &[&Share]) -> SetStatus {
+    let first = shares[0];
+    let mut conflicts = Vec::new();
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+
+    for &share in shares {
+        let describe = format!(
+            "share with member index {} in group {}",
+            share.member_index, share.group_index
+        );
+        if share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+            || share.group_threshold != first.group_threshold
+            || share.group_count != first.group_count
+        {
+            conflicts.push(format!(
+                "{} has different set parameters than the other shares",
+                describe
+            ));
+            continue;
+        }
+        if share.group_index >= share.group_count {
+            conflicts.push(format!(
+                "{} has a group index outside the {} groups of the set",
+                describe, share.group_count
+            ));
+            continue;
+        }
+
+        let members = groups.entry(share.group_index).or_default();
+        if let Some(other) = members
+            .iter()
+            .find(|other| other.member_index == share.member_index)
+        {
+            if other.value != share.value {
+                conflicts.push(format!(
+                    "{} appears more than once with different values",
+                    describe
+                ));
+            }
+            continue;
+        }
+        if members
+            .first()
+            .is_some_and(|other| other.member_threshold != share.member_threshold)
+        {
+            conflicts.push(format!(
+                "{} has a different member threshold than the rest of its group",
+                describe
+            ));
+            continue;
+        }
+        members.push(share);
+    }
+
+    SetStatus {
+        identifier: first.identifier,
+        extendable: first.extendable,
+        iteration_exponent: first.iteration_exponent,
+        group_threshold: first.group_threshold,
+        group_count: first.group_count,
+        groups: groups
+            .into_iter()
+            .map(|(group_index, members)| {
+                let mut member_indices: Vec<u8> =
+                    members.iter().map(|share| share.member_index).collect();
+                member_indices.sort_unstable();
+                GroupStatus {
+                    group_index,
+                    member_threshold: members[0].member_threshold,
+                    member_indices,
+                }
+            })
+            .collect(),
+        conflicts,
+    }
+}
@@ -0,0 +1,196 @@
+//! Typo-tolerant word suggestions
+//!
+//! Words are ranked by Damerau-Levenshtein distance in its optimal string
+//! alignment form: insertions, deletions, substitutions and transpositions of
+//! adjacent letters each count as one edit. Substituting a letter with a
+//! neighbouring key on a QWERTY keyboard is the most common slip, so it only
+//! counts as half an edit when ranking.
+
+use crate::wordlist;
+
+/// Letter rows of a QWERTY keyboard; each row is shifted right by about half a key
+const QWERTY_ROWS: [&[u8]; 3] = [b"qwertyuiop", b"asdfghjkl", b"zxcvbnm"];
+
+/// Maximum number of edits between an input and a suggested word
+pub const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Number of suggestions attached to [`crate::Error::WordNotFound`]
+pub const ERROR_SUGGESTIONS: usize = 3;
+
+/// A word suggested for a misspelled input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The suggested word from the wordlist
+    pub word: &'static str,
+    /// Number of edits between the input and the word
+    pub distance: usize,
+}
+
+/// Row and column of a letter on a QWERTY keyboard
+fn key_position(key: u8) -> Option<(usize, usize)> {
+    QWERTY_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.iter().position(|&k| k == key).map(|col| (row, col)))
+}
+
+/// Whether two letters are next to each other on a QWERTY keyboard
+pub fn keys_adjacent(a: char, b: char) -> bool {
+    if !a.is_ascii() || !b.is_ascii() {
+        return false;
+    }
+    let (Some((ra, ca)), Some((rb, cb))) = (
+        key_position(a.to_ascii_lowercase() as u8),
+        key_position(b.to_ascii_lowercase() as u8),
+    ) else {
+        return false;
+    };
+    match (ra, rb) {
+        _ if ra == rb => ca.abs_diff(cb) == 1,
+        // The lower row is shifted right: a key touches the keys at the same
+        // column and one column to the left below it
+        _ if rb == ra + 1 => cb == ca || cb + 1 == ca,
+        _ if ra == rb + 1 => ca == cb || ca + 1 == cb,
+        _ => false,
+    }
+}
+
+/// Optimal string alignment distance with a custom substitution cost
+///
+/// Insertions, deletions and transpositions cost `unit`.
+fn alignment_cost(
+    a: &[u8],
+    b: &[u8],
+    unit: usize,
+    substitution: impl Fn(u8, u8) -> usize,
+) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i * unit;
+    }
+    rows[0] = (0..=b.len()).map(|j| j * unit).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let replace = if a[i - 1] == b[j - 1] {
+                0
+            } else {
+                substitution(a[i - 1], b[j - 1])
+            };
+            let mut cost = (rows[i - 1][j] + unit)
+                .min(rows[i][j - 1] + unit)
+                .min(rows[i - 1][j - 1] + replace);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cost = cost.min(rows[i - 2][j - 2] + unit);
+            }
+            rows[i][j] = cost;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Damerau-Levenshtein (optimal string alignment) distance between two words
+///
+/// # Example
+/// ```
+/// use slip39_calculator::suggest::edit_distance;
+///
+/// assert_eq!(edit_distance("acedemic", "academic"), 1);
+/// assert_eq!(edit_distance("acadmeic", "academic"), 1);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    alignment_cost(a.as_bytes(), b.as_bytes(), 1, |_, _| 1)
+}
+
+/// Edit cost in half edits, where substituting a neighbouring key counts as half
+fn keyboard_cost(a: &str, b: &str) -> usize {
+    alignment_cost(a.as_bytes(), b.as_bytes(), 2, |x, y| {
+        if keys_adjacent(char::from(x), char::from(y)) {
+            1
+        } else {
+            2
+        }
+    })
+}
+
+/// Suggest wordlist entries for a misspelled word
+///
+/// Words within [`MAX_SUGGESTION_DISTANCE`] edits are ranked by keyboard-weighted
+/// cost, then by edit distance, then alphabetically.
+///
+/// # Arguments
+/// * `input` - The misspelled word (case-insensitive, trimmed)
+/// * `limit` - Maximum number of suggestions to return
+///
+/// # Returns
+/// * `Vec<Suggestion>` - The best suggestions first (empty if nothing is close)
+///
+/// # Example
+/// ```
+/// use slip39_calculator::suggest::suggest;
+///
+/// assert_eq!(suggest("acedemic", 3)[0].word, "academic");
+/// ```
+pub fn suggest(input: &str, limit: usize) -> Vec<Suggestion> {
+    let normalized = input.trim().to_lowercase();
+    let mut ranked: Vec<(usize, Suggestion)> = wordlist()
+        .iter()
+        // Lengths that differ too much cannot be within the distance limit
+        .filter(|word| word.len().abs_diff(normalized.len()) <= MAX_SUGGESTION_DISTANCE)
+        .map(|&word| Suggestion {
+            word,
+            distance: edit_distance(&normalized, word),
+        })
+        .filter(|s| s.distance <= MAX_SUGGESTION_DISTANCE)
+        .map(|s| (keyboard_cost(&normalized, s.word), s))
+        .collect();
+
+    ranked.sort_by(|(cost_a, a), (cost_b, b)| {
+        (cost_a, a.distance, a.word).cmp(&(cost_b, b.distance, b.word))
+    });
+    ranked.into_iter().take(limit).map(|(_, s)| s).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "acid"), 4);
+        assert_eq!(edit_distance("acid", "acid"), 0);
+        assert_eq!(edit_distance("aicd", "acid"), 1);
+        assert_eq!(edit_distance("acd", "acid"), 1);
+        assert_eq!(edit_distance("acidd", "acid"), 1);
+        assert_eq!(edit_distance("zcod", "acid"), 2);
+    }
+
+    #[test]
+    fn test_keys_adjacent() {
+        assert!(keys_adjacent('q', 'w'));
+        assert!(keys_adjacent('s', 'w'));
+        assert!(keys_adjacent('s', 'e'));
+        assert!(keys_adjacent('s', 'z'));
+        assert!(keys_adjacent('s', 'x'));
+        assert!(keys_adjacent('S', 'd'));
+        assert!(!keys_adjacent('s', 'c'));
+        assert!(!keys_adjacent('q', 'p'));
+        assert!(!keys_adjacent('1', '2'));
+    }
+
+    #[test]
+    fn test_keyboard_neighbour_ranks_first() {
+        // "o" and "i" are neighbouring keys
+        let suggestions = suggest("acod", 5);
+        assert_eq!(suggestions[0].word, "acid");
+        assert!(suggestions
+            .iter()
+            .all(|s| s.distance <= MAX_SUGGESTION_DISTANCE));
+    }
+
+    #[test]
+    fn test_no_suggestions_for_distant_input() {
+        assert!(suggest("qqqqqqqq", 5).is_empty());
+        assert!(suggest("academic", 0).is_empty());
+    }
+}
@@ -11,9 +11,12 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph},
     Frame, Terminal,
 };
-use slip39_calculator::{decode, encode, wordlist};
+use slip39_calculator::{decode, encode, suggest, wordlist};
 use std::{error::Error, io};
 
+/// Number of typo corrections shown when no word starts with the input
+const MAX_CORRECTIONS: usize = 7;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum InputMode {
     Word,
@@ -53,6 +56,8 @@ pub struct App {
     pub suggestions: Vec<String>,
     /// Index of the selected suggestion in the carousel
     pub suggestion_index: usize,
+    /// Suggestions are typo corrections because no word starts with the input
+    pub suggestions_corrected: bool,
     /// List of words added by the user (max 20)
     pub saved_words: Vec<String>,
     /// Index of the currently selected saved word (for viewing grid)
@@ -80,6 +85,7 @@ impl App {
             input: String::new(),
             suggestions: Vec::new(),
             suggestion_index: 0,
+            suggestions_corrected: false,
             saved_words: Vec::new(),
             saved_index: None,
             all_words: wordlist().iter().map(|s| s.to_string()).collect(),
@@ -104,6 +110,15 @@ impl App {
                 .collect();
         }
 
+        // Fall back to the closest words when the input is not a prefix of any word
+        self.suggestions_corrected = self.suggestions.is_empty() && !self.input.is_empty();
+        if self.suggestions_corrected {
+            self.suggestions = suggest(&self.input, MAX_CORRECTIONS)
+                .iter()
+                .map(|s| s.word.to_string())
+                .collect();
+        }
+
         // Reset index safely
         if self.suggestions.is_empty() || self.suggestion_index >= self.suggestions.len() {
             self.suggestion_index = 0;
@@ -287,15 +302,13 @@ fn update(app: &mut App, msg: Msg) {
                     }
                 }
             }
-            Msg::Up => {
-                if !app.saved_words.is_empty() {
-                    if let Some(curr) = app.saved_index {
-                        if curr > 0 {
-                            app.saved_index = Some(curr - 1);
-                        }
-                    } else {
-                        app.saved_index = Some(app.saved_words.len() - 1);
+            Msg::Up if !app.saved_words.is_empty() => {
+                if let Some(curr) = app.saved_index {
+                    if curr > 0 {
+                        app.saved_index = Some(curr - 1);
                     }
+                } else {
+                    app.saved_index = Some(app.saved_words.len() - 1);
                 }
             }
             Msg::Down => {
@@ -420,9 +433,12 @@ fn render_carousel(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .title(" Suggestions ");
+    let title = if app.suggestions_corrected {
+        " Did you mean? "
+    } else {
+        " Suggestions "
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
 
     let mut spans = Vec::new();
 
@@ -0,0 +1,362 @@
+//! Typed index of a word in the SLIP-39 wordlist
+//!
+//! A [`WordIndex`] is always in range (0-1023), so conversions to a word or to
+//! its 10-bit representation cannot fail once the index has been built.
+
+use crate::{find_by_prefix, word_indices, wordlist, Error};
+use std::fmt;
+use std::str::FromStr;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+
+/// Number of bits encoded by each word
+const BITS: usize = 10;
+
+/// Length of the longest word in the wordlist
+const MAX_WORD_LENGTH: usize = 8;
+
+/// Index of a word in the SLIP-39 wordlist (0-1023)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WordIndex(u16);
+
+impl WordIndex {
+    /// Number of words in the wordlist
+    pub const COUNT: u16 = 1 << BITS;
+
+    /// Number of leading letters that identify every word uniquely
+    pub const ABBREVIATION_LENGTH: usize = 4;
+
+    /// Create an index, checking that it is in range
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The index
+    /// * `Err(Error::IndexOutOfRange)` - If index is > 1023
+    pub fn new(index: u16) -> Result<Self, Error> {
+        if index < Self::COUNT {
+            Ok(WordIndex(index))
+        } else {
+            Err(Error::IndexOutOfRange(usize::from(index)))
+        }
+    }
+
+    /// Look up the index of a word (case-insensitive, trimmed)
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The position of the word in the wordlist
+    /// * `Err(Error::WordNotFound)` - If word is not in the wordlist
+    pub fn from_word(word: &str) -> Result<Self, Error> {
+        let normalized = word.trim().to_lowercase();
+        word_indices()
+            .get(normalized.as_str())
+            .map(|&index| WordIndex(index))
+            .ok_or_else(|| Error::word_not_found(word))
+    }
+
+    /// Look up the index of a word by its abbreviation or any other unique prefix
+    ///
+    /// Every four-letter abbreviation is unique; a full word is always accepted,
+    /// even when it is also the prefix of another word.
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The position of the only matching word
+    /// * `Err(Error::WordNotFound)` - If no words start with prefix
+    /// * `Err(Error::AmbiguousPrefix)` - If multiple words start with prefix
+    pub fn from_prefix(prefix: &str) -> Result<Self, Error> {
+        let word = find_by_prefix(prefix)?;
+        // find_by_prefix only returns words taken from the wordlist
+        Ok(WordIndex(word_indices()[word.as_str()]))
+    }
+
+    /// Look up the index of a word without branching on its content
+    ///
+    /// The word is compared against every entry of the wordlist with
+    /// constant-time comparisons, so the time taken only depends on the length
+    /// of the input. Use this for words of a secret mnemonic.
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The position of the word in the wordlist
+    /// * `Err(Error::WordNotFound)` - If word is not in the wordlist
+    pub fn from_word_ct(word: &str) -> Result<Self, Error> {
+        let scan = Scan::new(word);
+        if bool::from(scan.exact) {
+            Ok(WordIndex(scan.exact_index))
+        } else {
+            Err(Error::word_not_found(word))
+        }
+    }
+
+    /// Look up a word or unique prefix without branching on its content
+    ///
+    /// Accepts the same input as [`WordIndex::from_prefix`], with the
+    /// constant-time comparisons of [`WordIndex::from_word_ct`]. Only when the
+    /// input does not resolve is [`WordIndex::from_prefix`] called to describe
+    /// the failure. Use this for words and abbreviations of a secret mnemonic.
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The position of the word, or of the only word starting with prefix
+    /// * `Err(Error::WordNotFound)` - If no words start with prefix
+    /// * `Err(Error::AmbiguousPrefix)` - If multiple words start with prefix
+    pub fn from_prefix_ct(prefix: &str) -> Result<Self, Error> {
+        let scan = Scan::new(prefix);
+        let unique = scan.exact | scan.prefix_count.ct_eq(&1);
+        if bool::from(unique) {
+            let index = u16::conditional_select(&scan.prefix_index, &scan.exact_index, scan.exact);
+            Ok(WordIndex(index))
+        } else {
+            Self::from_prefix(prefix)
+        }
+    }
+
+    /// Parse a 10-bit binary string (e.g. "0000000001")
+    ///
+    /// # Returns
+    /// * `Ok(WordIndex)` - The index encoded by the bits
+    /// * `Err(Error::InvalidBinaryLength)` - If the string is not exactly 10 characters
+    /// * `Err(Error::InvalidBinary)` - If the string contains characters other than '0' and '1'
+    pub fn from_bits(bits: &str) -> Result<Self, Error> {
+        if bits.len() != BITS {
+            return Err(Error::InvalidBinaryLength(bits.len()));
+        }
+        if !bits.chars().all(|c| c == '0' || c == '1') {
+            return Err(Error::InvalidBinary(
+                "Binary string must only contain '0' and '1'".to_string(),
+            ));
+        }
+        let index =
+            u16::from_str_radix(bits, 2).map_err(|e| Error::InvalidBinary(e.to_string()))?;
+        Self::new(index)
+    }
+
+    /// The word at this index
+    pub fn word(self) -> &'static str {
+        // The index is in range and the wordlist holds exactly 1024 words
+        wordlist()[usize::from(self.0)]
+    }
+
+    /// The four-letter abbreviation of the word (shorter words are returned whole)
+    pub fn abbreviation(self) -> &'static str {
+        let word = self.word();
+        &word[..word.len().min(Self::ABBREVIATION_LENGTH)]
+    }
+
+    /// The 10-bit binary representation (e.g. "0000000001")
+    pub fn to_bits(self) -> String {
+        format!("{:010b}", self.0)
+    }
+
+    /// The index as an integer
+    pub fn value(self) -> u16 {
+        self.0
+    }
+}
+
+/// Matches of an input against every word of the wordlist
+///
+/// Every word is compared in constant time; the only branches depend on the
+/// length of the input and of the (public) wordlist entries.
+struct Scan {
+    /// Whether the input is a whole word
+    exact: Choice,
+    /// Index of the word equal to the input
+    exact_index: u16,
+    /// Number of words starting with the input
+    prefix_count: u16,
+    /// Index of the last word starting with the input
+    prefix_index: u16,
+}
+
+impl Scan {
+    /// Compare an input (case-insensitive, trimmed) against the wordlist
+    fn new(input: &str) -> Self {
+        let input = input.trim().as_bytes();
+        let length = input.len().min(MAX_WORD_LENGTH);
+        let too_long = Choice::from(u8::from(input.len() > MAX_WORD_LENGTH));
+        let mut normalized = [0u8; MAX_WORD_LENGTH];
+        for (n, &b) in normalized.iter_mut().zip(input) {
+            *n = ascii_lowercase_ct(b);
+        }
+
+        let mut scan = Scan {
+            exact: Choice::from(0),
+            exact_index: 0,
+            prefix_count: 0,
+            prefix_index: 0,
+        };
+        for (i, w) in wordlist().iter().enumerate() {
+            let mut candidate = [0u8; MAX_WORD_LENGTH];
+            candidate[..w.len()].copy_from_slice(w.as_bytes());
+            let is_prefix = normalized[..length].ct_eq(&candidate[..length])
+                & Choice::from(u8::from(w.len() >= length))
+                & !too_long;
+            let is_exact = is_prefix & Choice::from(u8::from(w.len() == length));
+            scan.exact_index.conditional_assign(&(i as u16), is_exact);
+            scan.exact |= is_exact;
+            scan.prefix_index.conditional_assign(&(i as u16), is_prefix);
+            scan.prefix_count += u16::from(is_prefix.unwrap_u8());
+        }
+        scan
+    }
+}
+
+/// Lowercase an ASCII letter without branching on its value
+fn ascii_lowercase_ct(b: u8) -> u8 {
+    let is_upper = b.ct_gt(&(b'A' - 1)) & (b'Z' + 1).ct_gt(&b);
+    b | u8::conditional_select(&0, &0x20, is_upper)
+}
+
+impl fmt::Display for WordIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for WordIndex {
+    type Err = Error;
+
+    /// Parse a decimal index (e.g. "1023")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index: usize = s
+            .trim()
+            .parse()
+            .map_err(|_| Error::InvalidIndex(s.to_string()))?;
+        Self::try_from(index)
+    }
+}
+
+impl TryFrom<usize> for WordIndex {
+    type Error = Error;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        u16::try_from(index)
+            .map_err(|_| Error::IndexOutOfRange(index))
+            .and_then(Self::new)
+    }
+}
+
+impl TryFrom<u16> for WordIndex {
+    type Error = Error;
+
+    fn try_from(index: u16) -> Result<Self, Self::Error> {
+        Self::new(index)
+    }
+}
+
+impl From<WordIndex> for u16 {
+    fn from(index: WordIndex) -> Self {
+        index.0
+    }
+}
+
+impl From<WordIndex> for usize {
+    fn from(index: WordIndex) -> Self {
+        usize::from(index.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        assert!(WordIndex::new(1023).is_ok());
+        assert!(matches!(
+            WordIndex::new(1024),
+            Err(Error::IndexOutOfRange(1024))
+        ));
+        assert!(matches!(
+            WordIndex::try_from(70000usize),
+            Err(Error::IndexOutOfRange(70000))
+        ));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let index = WordIndex::from_word(" Acid ").unwrap();
+        assert_eq!(index.value(), 1);
+        assert_eq!(index.word(), "acid");
+        assert_eq!(index.to_bits(), "0000000001");
+        assert_eq!(WordIndex::from_bits("0000000001").unwrap(), index);
+        assert_eq!(index.to_string(), "1");
+        assert_eq!("1".parse::<WordIndex>().unwrap(), index);
+        assert_eq!(usize::from(index), 1);
+    }
+
+    #[test]
+    fn test_constant_time_lookup_matches() {
+        for (i, &word) in wordlist().iter().enumerate() {
+            assert_eq!(usize::from(WordIndex::from_word_ct(word).unwrap()), i);
+        }
+        assert_eq!(WordIndex::from_word_ct(" ZERO ").unwrap().value(), 1023);
+        // Prefixes, extensions and padding bytes must not match
+        for word in [
+            "acad",
+            "academics",
+            "academic\0",
+            "acid\0",
+            "",
+            "@cid",
+            "[cid",
+        ] {
+            assert!(matches!(
+                WordIndex::from_word_ct(word),
+                Err(Error::WordNotFound { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_constant_time_prefix_lookup_matches() {
+        for (i, &word) in wordlist().iter().enumerate() {
+            let index = WordIndex::try_from(i).unwrap();
+            assert_eq!(WordIndex::from_prefix_ct(word).unwrap(), index);
+            assert_eq!(
+                WordIndex::from_prefix_ct(index.abbreviation()).unwrap(),
+                index
+            );
+        }
+        assert_eq!(
+            WordIndex::from_prefix_ct(" ACADE ").unwrap().word(),
+            "academic"
+        );
+        assert!(matches!(
+            WordIndex::from_prefix_ct("ac"),
+            Err(Error::AmbiguousPrefix(..))
+        ));
+        for word in ["academics", "acid\0", "xyz", "@cid"] {
+            assert!(matches!(
+                WordIndex::from_prefix_ct(word),
+                Err(Error::WordNotFound { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_abbreviations_are_unique() {
+        for (i, &word) in wordlist().iter().enumerate() {
+            let index = WordIndex::try_from(i).unwrap();
+            assert_eq!(index.abbreviation(), &word[..4]);
+            assert_eq!(WordIndex::from_prefix(index.abbreviation()).unwrap(), index);
+        }
+        assert_eq!(WordIndex::from_prefix("ACAD").unwrap().word(), "academic");
+        assert!(matches!(
+            WordIndex::from_prefix("ac"),
+            Err(Error::AmbiguousPrefix(..))
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            "one".parse::<WordIndex>(),
+            Err(Error::InvalidIndex(_))
+        ));
+        assert!(matches!(
+            "1024".parse::<WordIndex>(),
+            Err(Error::IndexOutOfRange(1024))
+        ));
+        assert!(matches!(
+            WordIndex::from_bits("01"),
+            Err(Error::InvalidBinaryLength(2))
+        ));
+    }
+}
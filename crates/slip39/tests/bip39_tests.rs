@@ -0,0 +1,112 @@
+//! Integration tests for BIP-39 mnemonics
+//!
+//! Verifies:
+//! - Official test vectors (passphrase "TREZOR") for entropy, mnemonic and seed
+//! - Abbreviated and mixed-case mnemonics decode to the same entropy
+//! - Invalid entropy lengths, mnemonic lengths and checksums are rejected
+//! - The entropy of every mnemonic length round-trips through SLIP-39 shares
+
+use slip39_calculator::{bip39, combine, split_groups, Error};
+
+/// (entropy, mnemonic, seed) from the BIP-39 reference vectors
+const VECTORS: [(&str, &str, &str); 5] = [
+    (
+        "00000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    ),
+    (
+        "80808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+    ),
+];
+
+#[test]
+fn test_official_vectors() {
+    for (entropy, mnemonic, seed) in VECTORS {
+        let entropy = hex::decode(entropy).unwrap();
+        assert_eq!(bip39::entropy_to_mnemonic(&entropy).unwrap(), mnemonic);
+        assert_eq!(bip39::mnemonic_to_entropy(mnemonic).unwrap(), entropy);
+        assert_eq!(
+            hex::encode(bip39::mnemonic_to_seed(mnemonic, "TREZOR").unwrap()),
+            seed,
+            "{}",
+            mnemonic
+        );
+    }
+}
+
+#[test]
+fn test_abbreviated_mnemonic() {
+    let (entropy, mnemonic, seed) = VECTORS[2];
+    let abbreviated: Vec<String> = mnemonic
+        .split(' ')
+        .map(|w| w[..w.len().min(4)].to_uppercase())
+        .collect();
+    let abbreviated = abbreviated.join("  ");
+    assert_eq!(
+        hex::encode(bip39::mnemonic_to_entropy(&abbreviated).unwrap()),
+        entropy
+    );
+    assert_eq!(
+        hex::encode(bip39::mnemonic_to_seed(&abbreviated, "TREZOR").unwrap()),
+        seed
+    );
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    assert!(matches!(
+        bip39::entropy_to_mnemonic(&[0u8; 15]),
+        Err(Error::InvalidEntropyLength(120))
+    ));
+    assert!(matches!(
+        bip39::entropy_to_mnemonic(&[0u8; 18]),
+        Err(Error::InvalidEntropyLength(144))
+    ));
+    assert!(matches!(
+        bip39::mnemonic_to_entropy(&["abandon"; 12].join(" ")),
+        Err(Error::InvalidBip39Checksum)
+    ));
+    assert!(matches!(
+        bip39::mnemonic_to_entropy("abandon about"),
+        Err(Error::InvalidBip39MnemonicLength(2))
+    ));
+    assert!(matches!(
+        bip39::mnemonic_to_entropy("academic acid acne"),
+        Err(Error::Bip39WordNotFound(_))
+    ));
+    assert!(matches!(
+        bip39::mnemonic_to_seed(VECTORS[0].1, "pässword"),
+        Err(Error::InvalidPassphrase)
+    ));
+}
+
+#[test]
+fn test_entropy_roundtrips_through_shares() {
+    for length in [16, 20, 24, 28, 32] {
+        let entropy: Vec<u8> = (0..length as u8).map(|i| i.wrapping_mul(37)).collect();
+        let mnemonic = bip39::entropy_to_mnemonic(&entropy).unwrap();
+
+        let secret = bip39::mnemonic_to_entropy(&mnemonic).unwrap();
+        let groups = split_groups(&secret, b"TREZOR", true, 0, 1, &[(2, 3)]).unwrap();
+        let recovered = combine(&groups[0][1..], b"TREZOR").unwrap();
+        assert_eq!(bip39::entropy_to_mnemonic(&recovered).unwrap(), mnemonic);
+    }
+}
@@ -0,0 +1,41 @@
+//! Integration tests for RS1024 checksum verification
+//!
+//! Verifies:
+//! - A known-good SLIP-39 share passes verification
+//! - Any single-word substitution is rejected
+//! - Checksums created from word indices round-trip
+
+use slip39_calculator::{
+    create_checksum, mnemonic_to_indices, verify_checksum, verify_mnemonic_checksum, Customization,
+    Error,
+};
+
+const SHARE: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+#[test]
+fn test_valid_share_checksum() {
+    assert!(verify_mnemonic_checksum(SHARE).unwrap());
+    assert!(verify_mnemonic_checksum(&SHARE.to_uppercase()).unwrap());
+}
+
+#[test]
+fn test_last_word_changed() {
+    let corrupted = SHARE.replace("keyboard", "kidney");
+    assert!(!verify_mnemonic_checksum(&corrupted).unwrap());
+}
+
+#[test]
+fn test_unknown_word() {
+    let result = verify_mnemonic_checksum("duckling notaword academic");
+    assert!(matches!(result, Err(Error::WordNotFound { word, .. }) if word == "notaword"));
+}
+
+#[test]
+fn test_recreate_checksum_from_indices() {
+    let indices = mnemonic_to_indices(SHARE).unwrap();
+    assert_eq!(indices.len(), 20);
+
+    let (data, checksum) = indices.split_at(17);
+    assert_eq!(create_checksum(data, Customization::Shamir), checksum);
+    assert!(verify_checksum(&indices, Customization::Shamir));
+}
@@ -0,0 +1,299 @@
+//! Integration tests for recovering a master secret
+//!
+//! Verifies:
+//! - A known share decrypts to its master secret with the passphrase
+//! - Split followed by combine round-trips for single groups and group schemes
+//! - Surplus and duplicate shares are tolerated
+//! - Surplus shares and groups locate corrupted shares
+//! - Missing groups/members and mixed share sets are rejected
+//! - Resharing issues a new layout that restores the same secret
+//! - Mixed old and reshared shares are reported by status and rejected by combine
+
+use slip39_calculator::{
+    combine, combine_mnemonics, recover_ems, reshare, share_status, split, split_ems, split_groups,
+    Error, Share,
+};
+
+const SECRET: [u8; 16] = [
+    0xbb, 0x54, 0xaa, 0xc4, 0xb8, 0x9d, 0xc8, 0x68, 0xba, 0x37, 0xd9, 0xcc, 0x21, 0xb2, 0xce, 0xce,
+];
+
+#[test]
+fn test_combine_known_share() {
+    let share = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+    assert_eq!(combine_mnemonics(&[share], b"TREZOR").unwrap(), SECRET);
+
+    // The share value itself is the encrypted master secret
+    let ems = recover_ems(&[Share::from_mnemonic(share).unwrap()]).unwrap();
+    assert_eq!(ems.ciphertext, Share::from_mnemonic(share).unwrap().value);
+    assert_ne!(ems.decrypt(b"").unwrap(), SECRET);
+}
+
+#[test]
+fn test_split_combine_with_passphrase() {
+    let groups = split_groups(&SECRET, b"TREZOR", false, 2, 1, &[(2, 3)]).unwrap();
+    assert_eq!(groups[0][0].iteration_exponent, 2);
+    assert_eq!(combine(&groups[0][1..], b"TREZOR").unwrap(), SECRET);
+    assert_ne!(combine(&groups[0][1..], b"").unwrap(), SECRET);
+}
+
+#[test]
+fn test_split_combine_single_group() {
+    let shares = split(&SECRET, b"", 3, 5).unwrap();
+    assert_eq!(combine(&shares[..3], b"").unwrap(), SECRET);
+    assert_eq!(combine(&shares[2..], b"").unwrap(), SECRET);
+    assert_eq!(
+        combine(
+            &[shares[4].clone(), shares[0].clone(), shares[2].clone()],
+            b""
+        )
+        .unwrap(),
+        SECRET
+    );
+
+    let mnemonics: Vec<String> = shares.iter().map(|s| s.to_mnemonic().unwrap()).collect();
+    assert_eq!(combine_mnemonics(&mnemonics[1..4], b"").unwrap(), SECRET);
+}
+
+#[test]
+fn test_split_combine_groups() {
+    let secret = [0x5a; 32];
+    let groups = split_groups(&secret, b"", false, 0, 2, &[(2, 3), (3, 5), (1, 1)]).unwrap();
+
+    let mut shares = groups[1][1..4].to_vec();
+    shares.extend_from_slice(&groups[2]);
+    assert_eq!(combine(&shares, b"").unwrap(), secret);
+
+    let mut shares = groups[0][..2].to_vec();
+    shares.extend_from_slice(&groups[1][..3]);
+    assert_eq!(combine(&shares, b"").unwrap(), secret);
+}
+
+#[test]
+fn test_combine_tolerates_duplicates_and_surplus() {
+    let shares = split(&SECRET, b"", 2, 4).unwrap();
+    let with_duplicate = vec![shares[0].clone(), shares[0].clone(), shares[3].clone()];
+    assert_eq!(combine(&with_duplicate, b"").unwrap(), SECRET);
+    assert_eq!(combine(&shares, b"").unwrap(), SECRET);
+}
+
+#[test]
+fn test_combine_insufficient_members() {
+    let shares = split(&SECRET, b"", 3, 5).unwrap();
+    assert!(matches!(
+        combine(&shares[..2], b""),
+        Err(Error::InsufficientShares(_))
+    ));
+    assert!(matches!(
+        combine(&[], b""),
+        Err(Error::InsufficientShares(_))
+    ));
+}
+
+#[test]
+fn test_combine_insufficient_groups() {
+    let groups = split_groups(&SECRET, b"", false, 0, 2, &[(2, 3), (2, 3)]).unwrap();
+    let mut shares = groups[0][..2].to_vec();
+    shares.push(groups[1][0].clone());
+
+    let err = combine(&shares, b"").unwrap_err();
+    assert!(matches!(err, Error::InsufficientShares(_)));
+    assert!(err.to_string().contains("group 1 has 1 of 2 shares"));
+}
+
+#[test]
+fn test_combine_rejects_mixed_sets() {
+    let first = split(&SECRET, b"", 2, 3).unwrap();
+    let mut second = split(&SECRET, b"", 2, 3).unwrap();
+    // Force distinct identifiers
+    second[1].identifier = first[0].identifier ^ 1;
+    assert!(matches!(
+        combine(&[first[0].clone(), second[1].clone()], b""),
+        Err(Error::InconsistentShares(_))
+    ));
+}
+
+#[test]
+fn test_combine_rejects_conflicting_member_index() {
+    let shares = split(&SECRET, b"", 2, 3).unwrap();
+    let mut forged = shares[0].clone();
+    forged.value[0] ^= 1;
+    assert!(matches!(
+        combine(&[shares[0].clone(), forged, shares[1].clone()], b""),
+        Err(Error::InconsistentShares(_))
+    ));
+}
+
+#[test]
+fn test_combine_detects_corrupted_value() {
+    let mut shares = split(&SECRET, b"", 2, 3).unwrap();
+    shares[1].value[5] ^= 0x80;
+    assert!(matches!(
+        combine(&shares[..2], b""),
+        Err(Error::InvalidDigest)
+    ));
+}
+
+#[test]
+fn test_combine_identifies_corrupted_surplus_share() {
+    let secret = [0x5Au8; 16];
+    for corrupted in 0..4 {
+        let mut shares = split(&secret, b"", 3, 4).unwrap();
+        shares[corrupted].value[3] ^= 0x01;
+        let err = combine(&shares, b"").unwrap_err();
+        assert!(matches!(err, Error::InconsistentShares(_)));
+        assert!(
+            err.to_string().contains(&format!(
+                "share with member index {} in group 0 does not match",
+                shares[corrupted].member_index
+            )),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn test_combine_identifies_several_corrupted_shares() {
+    let mut shares = split(&SECRET, b"", 2, 5).unwrap();
+    shares[1].value[0] ^= 0x10;
+    shares[4].value[7] ^= 0x02;
+    let message = combine(&shares, b"").unwrap_err().to_string();
+    assert!(message.contains("member index 1 in group 0"));
+    assert!(message.contains("member index 4 in group 0"));
+    assert!(!message.contains("member index 0 in group 0"));
+
+    // Without a consistent threshold subset the corruption cannot be located
+    assert!(matches!(
+        combine(
+            &[shares[0].clone(), shares[1].clone(), shares[4].clone()],
+            b""
+        ),
+        Err(Error::InvalidDigest)
+    ));
+}
+
+#[test]
+fn test_combine_identifies_corrupted_surplus_group() {
+    let mut groups = split_groups(&SECRET, b"", true, 0, 2, &[(1, 1), (1, 1), (1, 1)]).unwrap();
+    groups[2][0].value[0] ^= 0x01;
+    let shares: Vec<Share> = groups.into_iter().flatten().collect();
+    let message = combine(&shares, b"").unwrap_err().to_string();
+    assert!(message.contains("group 2 does not match"), "{}", message);
+}
+
+#[test]
+fn test_combine_known_extendable_share() {
+    // Extendable vectors of the current upstream vectors.json, which const/vectors.json predates
+    let share = "testify swimming academic academic column loyalty smear include exotic bedroom exotic wrist lobe cover grief golden smart junior estimate learn";
+    let parsed = Share::from_mnemonic(share).unwrap();
+    assert!(parsed.extendable);
+    assert_eq!(
+        hex::encode(combine_mnemonics(&[share], b"TREZOR").unwrap()),
+        "1679b4516e0ee5954351d288a838f45e"
+    );
+
+    let shares = [
+        "enemy favorite academic acid cowboy phrase havoc level response walnut budget painting inside trash adjust froth kitchen learn tidy punish",
+        "enemy favorite academic always academic sniff script carpet romp kind promise scatter center unfair training emphasis evening belong fake enforce",
+    ];
+    assert_eq!(
+        hex::encode(combine_mnemonics(&shares, b"TREZOR").unwrap()),
+        "48b1a4b80b8c209ad42c33672bdaa428"
+    );
+
+    let share = "impulse calcium academic academic alcohol sugar lyrics pajamas column facility finance tension extend space birthday rainbow swimming purple syndrome facility trial warn duration snapshot shadow hormone rhyme public spine counter easy hawk album";
+    assert_eq!(
+        hex::encode(combine_mnemonics(&[share], b"TREZOR").unwrap()),
+        "8340611602fe91af634a5f4608377b5235fa2d757c51d720c0c7656249a3035f"
+    );
+}
+
+#[test]
+fn test_split_combine_extendable() {
+    let groups = split_groups(&SECRET, b"TREZOR", true, 0, 1, &[(2, 3)]).unwrap();
+    assert!(groups[0].iter().all(|share| share.extendable));
+
+    let mnemonics: Vec<String> = groups[0].iter().map(|s| s.to_mnemonic().unwrap()).collect();
+    assert_eq!(
+        combine_mnemonics(&mnemonics[..2], b"TREZOR").unwrap(),
+        SECRET
+    );
+
+    // The same encrypted secret can be re-split under a new identifier
+    let mut ems = recover_ems(&groups[0]).unwrap();
+    ems.identifier ^= 0x1234;
+    let reissued = split_ems(&ems, 1, &[(2, 2)]).unwrap();
+    assert_eq!(combine(&reissued[0], b"TREZOR").unwrap(), SECRET);
+}
+
+#[test]
+fn test_combine_rejects_mixed_extendable_flags() {
+    let shares = split(&SECRET, b"", 2, 3).unwrap();
+    let mut other = shares[1].clone();
+    other.extendable = !other.extendable;
+    assert!(matches!(
+        combine(&[shares[0].clone(), other], b""),
+        Err(Error::InconsistentShares(_))
+    ));
+}
+
+#[test]
+fn test_reshare_extendable() {
+    let shares = split_groups(&SECRET, b"TREZOR", true, 0, 1, &[(2, 3)]).unwrap();
+    let reissued = reshare(&shares[0][1..], 2, &[(1, 1), (2, 3)], false).unwrap();
+    assert_eq!(reissued.len(), 2);
+    assert_eq!(reissued[1].len(), 3);
+    assert!(reissued
+        .iter()
+        .flatten()
+        .all(|share| share.identifier != shares[0][0].identifier && share.extendable));
+
+    let subset = [
+        reissued[0][0].clone(),
+        reissued[1][0].clone(),
+        reissued[1][2].clone(),
+    ];
+    assert_eq!(combine(&subset, b"TREZOR").unwrap(), SECRET);
+
+    // Old and new shares are told apart as two incomplete sets
+    let mixed = [shares[0][0].clone(), reissued[1][0].clone()];
+    let status = share_status(&mixed);
+    assert_eq!(status.len(), 2);
+    assert!(status.iter().all(|set| !set.is_recoverable()));
+    assert!(matches!(
+        combine(&mixed, b"TREZOR"),
+        Err(Error::InconsistentShares(_))
+    ));
+}
+
+#[test]
+fn test_reshare_non_extendable() {
+    let shares = split_groups(&SECRET, b"TREZOR", false, 0, 1, &[(2, 3)]).unwrap();
+    assert!(matches!(
+        reshare(&shares[0][..2], 1, &[(2, 3)], false),
+        Err(Error::InvalidParameters(_))
+    ));
+
+    // The identifier is part of the encryption salt and must be kept
+    let reissued = reshare(&shares[0][..2], 1, &[(2, 3)], true).unwrap();
+    assert!(reissued[0]
+        .iter()
+        .all(|share| share.identifier == shares[0][0].identifier && !share.extendable));
+    assert_eq!(combine(&reissued[0][1..], b"TREZOR").unwrap(), SECRET);
+
+    // Old and new shares look like a single recoverable set, but do not combine
+    let mixed = [shares[0][0].clone(), reissued[0][1].clone()];
+    let status = share_status(&mixed);
+    assert_eq!(status.len(), 1);
+    assert!(status[0].is_recoverable());
+    assert!(matches!(
+        combine(&mixed, b"TREZOR"),
+        Err(Error::InvalidDigest)
+    ));
+
+    assert!(matches!(
+        reshare(&shares[0][..1], 1, &[(3, 5)], true),
+        Err(Error::InsufficientShares(_))
+    ));
+}
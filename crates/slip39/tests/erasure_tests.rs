@@ -0,0 +1,103 @@
+//! Erasure recovery tests
+//!
+//! Verifies:
+//! - One, two and three erased words are recovered at any positions
+//! - Erasing the word that carries the extendable flag is recovered (up to two candidates with three erasures)
+//! - More than three erasures and wrong word counts are rejected
+//! - Erasure markers are accepted in mnemonics
+
+use slip39_calculator::{fill_erasures, fill_mnemonic_erasures, mnemonic_to_indices, split, Error};
+
+const SHARE: &str = "duckling enlarge academic academic agency result length solution fridge \
+                     kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+fn erase(indices: &[u16], positions: &[usize]) -> Vec<Option<u16>> {
+    indices
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| (!positions.contains(&i)).then_some(w))
+        .collect()
+}
+
+#[test]
+fn test_single_erasure_every_position() {
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    for position in 0..original.len() {
+        let completions = fill_erasures(&erase(&original, &[position])).unwrap();
+        assert_eq!(completions, vec![original.clone()], "position {}", position);
+    }
+}
+
+#[test]
+fn test_two_and_three_erasures() {
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    for positions in [
+        vec![0, 19],
+        vec![1, 2],
+        vec![5, 12],
+        vec![3, 10, 17],
+        vec![17, 18, 19],
+    ] {
+        let completions = fill_erasures(&erase(&original, &positions)).unwrap();
+        assert_eq!(
+            completions,
+            vec![original.clone()],
+            "positions {:?}",
+            positions
+        );
+    }
+}
+
+#[test]
+fn test_three_erasures_with_extendable_flag() {
+    // The flag selects the customization, and each customization has one solution
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    let completions = fill_erasures(&erase(&original, &[0, 1, 2])).unwrap();
+    assert!(completions.contains(&original));
+    assert!(completions.len() <= 2);
+}
+
+#[test]
+fn test_erasures_in_extendable_shares() {
+    let secret = [0x42u8; 32];
+    for share in split(&secret, b"", 2, 3).unwrap() {
+        let original = share.to_indices().unwrap();
+        for positions in [vec![1], vec![1, 20], vec![0, 7, 31]] {
+            let completions = fill_erasures(&erase(&original, &positions)).unwrap();
+            assert_eq!(
+                completions,
+                vec![original.clone()],
+                "positions {:?}",
+                positions
+            );
+        }
+    }
+}
+
+#[test]
+fn test_too_many_erasures() {
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    assert!(matches!(
+        fill_erasures(&erase(&original, &[0, 1, 2, 3])),
+        Err(Error::InvalidParameters(_))
+    ));
+}
+
+#[test]
+fn test_erasures_with_wrong_length() {
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    assert!(matches!(
+        fill_erasures(&erase(&original[..19], &[5])),
+        Err(Error::InvalidMnemonicLength(19))
+    ));
+}
+
+#[test]
+fn test_erasure_markers_in_mnemonic() {
+    let original = mnemonic_to_indices(SHARE).unwrap();
+    let marked = SHARE
+        .replace("fridge", "?")
+        .replace("kidney", "?")
+        .replace("duckling", "duck");
+    assert_eq!(fill_mnemonic_erasures(&marked).unwrap(), vec![original]);
+}
@@ -0,0 +1,98 @@
+//! Integration tests for parsing mnemonics from free-form text
+//!
+//! Verifies:
+//! - Numbering, commas, tabs, line breaks and mixed case are accepted
+//! - Four-letter abbreviations resolve to full words
+//! - Blank lines separate shares, and full-length lines are shares of their own
+//! - Errors report the line, column and byte span of the offending word
+
+use slip39_calculator::{combine, parse_mnemonic, parse_mnemonics, Error, Share};
+
+const SHARE: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+fn indices(mnemonic: &str) -> Vec<u16> {
+    Share::from_mnemonic(mnemonic)
+        .unwrap()
+        .to_indices()
+        .unwrap()
+}
+
+#[test]
+fn test_parse_numbered_and_punctuated() {
+    let numbered: String = SHARE
+        .split(' ')
+        .enumerate()
+        .map(|(i, word)| format!("{}. {}", i + 1, word.to_uppercase()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(parse_mnemonic(&numbered).unwrap().indices(), indices(SHARE));
+
+    let messy = SHARE
+        .replace(' ', ",\t")
+        .replacen("duckling", "1)Duckling", 1);
+    assert_eq!(parse_mnemonic(&messy).unwrap().indices(), indices(SHARE));
+}
+
+#[test]
+fn test_parse_abbreviations() {
+    let abbreviated = Share::from_mnemonic(SHARE)
+        .unwrap()
+        .to_abbreviations()
+        .unwrap();
+    assert_eq!(
+        parse_mnemonic(&abbreviated).unwrap().indices(),
+        indices(SHARE)
+    );
+}
+
+#[test]
+fn test_parse_several_shares() {
+    let shares = slip39_calculator::split(&[7u8; 16], b"", 2, 3).unwrap();
+    let mnemonics: Vec<String> = shares.iter().map(|s| s.to_mnemonic().unwrap()).collect();
+
+    // One share per line
+    let text = format!("{}\n{}\n", mnemonics[0], mnemonics[2]);
+    let parsed = parse_mnemonics(&text);
+    assert_eq!(parsed.len(), 2);
+
+    // Shares wrapped over several lines, separated by blank lines
+    let wrapped: Vec<String> = mnemonics
+        .iter()
+        .map(|m| {
+            let words: Vec<&str> = m.split(' ').collect();
+            format!("{}\n{}", words[..10].join(" "), words[10..].join(" "))
+        })
+        .collect();
+    let text = format!("\n{}\n\n  \n{}\n", wrapped[1], wrapped[0]);
+    let parsed: Vec<Share> = parse_mnemonics(&text)
+        .into_iter()
+        .map(|p| p.unwrap().to_share(&text).unwrap())
+        .collect();
+    assert_eq!(parsed, vec![shares[1].clone(), shares[0].clone()]);
+    assert_eq!(combine(&parsed, b"").unwrap(), [7u8; 16]);
+}
+
+#[test]
+fn test_parse_error_location() {
+    let text = "acid acne\n  acquire bogus acrobat";
+    let err = parse_mnemonic(text).unwrap_err();
+    assert_eq!(err.span, 20..25);
+    assert_eq!(&text[err.span.clone()], "bogus");
+    assert_eq!((err.line, err.column), (2, 11));
+    assert!(matches!(err.error, Error::WordNotFound { .. }));
+    assert!(err.to_string().starts_with("line 2, column 11:"));
+
+    // Share-level errors point at the start of the mnemonic
+    let text = format!("\n\n{}", SHARE.replace("keyboard", "kidney"));
+    let err = parse_mnemonics(&text)[0]
+        .as_ref()
+        .unwrap()
+        .to_share(&text)
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert!(matches!(err.error, Error::InvalidChecksum));
+    assert!(matches!(
+        Error::from(err),
+        Error::InvalidInput { line: 3, .. }
+    ));
+}
@@ -5,8 +5,9 @@
 //! - Ambiguous prefix detection
 //! - Error messages for ambiguous/not found
 //! - Case insensitivity and trimming with prefixes
+//! - Four-letter abbreviations of whole shares round-trip
 
-use slip39_calculator::{find_by_prefix, find_matches, Error};
+use slip39_calculator::{find_by_prefix, find_matches, split, Error, Share, WordIndex};
 
 #[test]
 fn test_find_matches() {
@@ -52,7 +53,7 @@ fn test_find_by_prefix_ambiguous() {
 fn test_find_by_prefix_not_found() {
     let result = find_by_prefix("xyz");
     assert!(result.is_err());
-    assert!(matches!(result, Err(Error::WordNotFound(_))));
+    assert!(matches!(result, Err(Error::WordNotFound { .. })));
 }
 
 #[test]
@@ -60,3 +61,22 @@ fn test_find_by_prefix_normalization() {
     // Should trim and lowercase before searching
     assert_eq!(find_by_prefix("  ACA  ").unwrap(), "academic");
 }
+
+#[test]
+fn test_share_abbreviations_roundtrip() {
+    for share in split(&[0xA5; 32], b"", 2, 3).unwrap() {
+        let abbreviated = share.to_abbreviations().unwrap();
+        assert!(abbreviated.split(' ').all(|word| word.len() == 4));
+        assert_eq!(Share::from_mnemonic(&abbreviated).unwrap(), share);
+    }
+}
+
+#[test]
+fn test_word_index_from_prefix() {
+    assert_eq!(WordIndex::from_prefix("zero").unwrap().value(), 1023);
+    assert_eq!(WordIndex::from_prefix("acti").unwrap().word(), "activity");
+    assert!(matches!(
+        WordIndex::from_prefix("xyz"),
+        Err(Error::WordNotFound { .. })
+    ));
+}
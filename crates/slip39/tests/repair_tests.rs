@@ -0,0 +1,101 @@
+//! Single-word repair tests
+//!
+//! Verifies:
+//! - A single substituted word is found at the right position and restores the share
+//! - An unknown word is replaced in place
+//! - Adjacent transpositions are only tried when requested
+//! - Valid shares, double errors and wrong lengths produce no candidates
+
+use slip39_calculator::{
+    mnemonic_to_indices, repair_indices, repair_mnemonic, CorrectionKind, Error,
+};
+
+const SHARE: &str = "duckling enlarge academic academic agency result length solution fridge \
+                     kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+fn indices() -> Vec<u16> {
+    mnemonic_to_indices(SHARE).unwrap()
+}
+
+#[test]
+fn test_substitution_is_repaired_at_every_position() {
+    let original = indices();
+    for position in 0..original.len() {
+        let mut corrupted = original.clone();
+        corrupted[position] = (corrupted[position] + 512) % 1024;
+
+        let corrections = repair_indices(&corrupted, false);
+        assert_eq!(corrections.len(), 1, "position {}", position);
+        assert_eq!(
+            corrections[0].kind,
+            CorrectionKind::Substitution {
+                position,
+                replacement: original[position],
+            }
+        );
+        assert_eq!(corrections[0].indices, original);
+    }
+}
+
+#[test]
+fn test_unknown_word_is_repaired() {
+    let expected = SHARE.split_whitespace().collect::<Vec<_>>().join(" ");
+    // "academic" is word 0, which must still be tried in place of an unknown word
+    for corrupted in [
+        SHARE.replace("fridge", "fridgx"),
+        SHARE.replacen("academic", "academix", 1),
+    ] {
+        let corrections = repair_mnemonic(&corrupted, true).unwrap();
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(corrections[0].to_mnemonic(), expected);
+    }
+}
+
+#[test]
+fn test_transposition_requires_flag() {
+    let mut corrupted = indices();
+    corrupted.swap(5, 6);
+
+    assert!(repair_indices(&corrupted, false)
+        .iter()
+        .all(|c| matches!(c.kind, CorrectionKind::Substitution { .. })));
+
+    let corrections = repair_indices(&corrupted, true);
+    assert!(corrections.iter().any(|c| {
+        c.kind == CorrectionKind::Transposition { position: 5 } && c.indices == indices()
+    }));
+}
+
+#[test]
+fn test_no_candidates() {
+    assert!(repair_indices(&indices(), true).is_empty());
+
+    let mut corrupted = indices();
+    corrupted[3] ^= 1;
+    corrupted[10] ^= 1;
+    assert!(repair_indices(&corrupted, false).is_empty());
+
+    let short = SHARE.rsplit_once(' ').unwrap().0;
+    assert!(matches!(
+        repair_mnemonic(short, false),
+        Err(Error::InvalidMnemonicLength(19))
+    ));
+
+    // The placeholder of an unknown word must not hide the wrong length
+    let short = short.replace("fridge", "fridgx");
+    assert!(matches!(
+        repair_mnemonic(&short, false),
+        Err(Error::InvalidMnemonicLength(19))
+    ));
+}
+
+#[test]
+fn test_two_unknown_words_are_rejected() {
+    let corrupted = SHARE
+        .replace("fridge", "fridgx")
+        .replace("kidney", "kidnex");
+    assert!(matches!(
+        repair_mnemonic(&corrupted, false),
+        Err(Error::WordNotFound { .. })
+    ));
+}
@@ -0,0 +1,179 @@
+//! Integration tests for share parsing and serialization
+//!
+//! Verifies:
+//! - Every header field of a known share is decoded
+//! - Parsing and serializing round-trips
+//! - Invalid length, padding and checksum are reported precisely
+//! - Out-of-range indices and header fields are rejected instead of corrupting the header
+//! - The field layout locates every field for 128- and 256-bit shares
+
+use slip39_calculator::{create_checksum, mnemonic_to_indices, Customization, Error, Share};
+
+const SHARE: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+#[test]
+fn test_parse_header_fields() {
+    let share = Share::from_mnemonic(SHARE).unwrap();
+    assert_eq!(share.identifier, 7945);
+    assert!(!share.extendable);
+    assert_eq!(share.iteration_exponent, 0);
+    assert_eq!(share.group_index, 0);
+    assert_eq!(share.group_threshold, 1);
+    assert_eq!(share.group_count, 1);
+    assert_eq!(share.member_index, 0);
+    assert_eq!(share.member_threshold, 1);
+    assert_eq!(share.value.len(), 16);
+}
+
+#[test]
+fn test_roundtrip() {
+    let share = Share::from_mnemonic(SHARE).unwrap();
+    assert_eq!(share.to_mnemonic().unwrap(), SHARE);
+    assert_eq!(share.checksum().unwrap(), [173, 196, 496]);
+}
+
+#[test]
+fn test_parse_accepts_prefixes() {
+    let abbreviated: Vec<&str> = SHARE.split(' ').map(|w| &w[..4.min(w.len())]).collect();
+    let share = Share::from_mnemonic(&abbreviated.join(" ")).unwrap();
+    assert_eq!(share.to_mnemonic().unwrap(), SHARE);
+}
+
+#[test]
+fn test_invalid_checksum() {
+    let corrupted = SHARE.replace("keyboard", "kidney");
+    assert!(matches!(
+        Share::from_mnemonic(&corrupted),
+        Err(Error::InvalidChecksum)
+    ));
+}
+
+#[test]
+fn test_invalid_length() {
+    let short: Vec<&str> = SHARE.split(' ').take(19).collect();
+    assert!(matches!(
+        Share::from_mnemonic(&short.join(" ")),
+        Err(Error::InvalidMnemonicLength(19))
+    ));
+
+    // 21 words leave 14 value words (140 bits), i.e. 12 bits of padding
+    let indices = vec![0u16; 21];
+    assert!(matches!(
+        Share::from_indices(&indices),
+        Err(Error::InvalidMnemonicLength(21))
+    ));
+}
+
+#[test]
+fn test_invalid_padding() {
+    let mut indices = mnemonic_to_indices(SHARE).unwrap();
+    indices.truncate(17);
+    // Set the highest padding bit of the first value word
+    indices[4] |= 0x200;
+    let checksum = create_checksum(&indices, Customization::Shamir);
+    indices.extend_from_slice(&checksum);
+
+    assert!(matches!(
+        Share::from_indices(&indices),
+        Err(Error::InvalidPadding)
+    ));
+}
+
+#[test]
+fn test_out_of_range_index() {
+    let mut indices = mnemonic_to_indices(SHARE).unwrap();
+    indices[1] = 1024;
+    assert!(matches!(
+        Share::from_indices(&indices),
+        Err(Error::IndexOutOfRange(1024))
+    ));
+}
+
+#[test]
+fn test_serialize_invalid_header_fields() {
+    let share = Share::from_mnemonic(SHARE).unwrap();
+    let invalid = [
+        Share {
+            group_threshold: 0,
+            ..share.clone()
+        },
+        Share {
+            member_threshold: 0,
+            ..share.clone()
+        },
+        Share {
+            member_threshold: 17,
+            ..share.clone()
+        },
+        Share {
+            group_index: 16,
+            ..share.clone()
+        },
+        Share {
+            identifier: 0x8000,
+            ..share.clone()
+        },
+        Share {
+            group_threshold: 2,
+            ..share.clone()
+        },
+    ];
+    for share in invalid {
+        assert!(
+            matches!(share.to_mnemonic(), Err(Error::InvalidShare(_))),
+            "{:?}",
+            share
+        );
+    }
+}
+
+#[test]
+fn test_serialize_value_lengths() {
+    let share = Share::from_mnemonic(SHARE).unwrap();
+    for length in [14, 15, 17, 31] {
+        let invalid = Share {
+            value: vec![0; length],
+            ..share.clone()
+        };
+        assert!(
+            matches!(invalid.to_mnemonic(), Err(Error::InvalidShare(_))),
+            "{} bytes",
+            length
+        );
+    }
+
+    // Every accepted length round-trips through the mnemonic
+    for length in [16, 18, 32, 64] {
+        let valid = Share {
+            value: (0..length as u8).collect(),
+            ..share.clone()
+        };
+        let parsed = Share::from_mnemonic(&valid.to_mnemonic().unwrap()).unwrap();
+        assert_eq!(parsed, valid, "{} bytes", length);
+    }
+}
+
+#[test]
+fn test_layout_spans() {
+    use slip39_calculator::share::layout;
+
+    let fields = layout(20).unwrap();
+    let names: Vec<&str> = fields.iter().map(|f| f.name).collect();
+    assert_eq!(names.first(), Some(&"identifier"));
+    assert_eq!(
+        &names[names.len() - 3..],
+        ["padding", "share value", "checksum"]
+    );
+    assert_eq!(fields[0].word_span(), (0, 1));
+
+    // 256-bit shares use 26 value words with 4 bits of padding
+    let fields = layout(33).unwrap();
+    let padding = fields.iter().find(|f| f.name == "padding").unwrap();
+    assert_eq!((padding.offset, padding.bits), (40, 4));
+    let value = fields.iter().find(|f| f.name == "share value").unwrap();
+    assert_eq!((value.offset, value.bits), (44, 256));
+    assert_eq!(value.word_span(), (4, 29));
+
+    assert!(matches!(layout(19), Err(Error::InvalidMnemonicLength(19))));
+    assert!(matches!(layout(21), Err(Error::InvalidMnemonicLength(21))));
+}
@@ -0,0 +1,106 @@
+//! Integration tests for splitting a master secret
+//!
+//! Verifies:
+//! - Split produces the requested number of parseable mnemonics
+//! - All shares of a set carry the same identifier and parameters
+//! - Invalid secret lengths and thresholds are rejected
+//! - Group layouts follow the SLIP-39 constraints
+
+use slip39_calculator::{split, split_groups, Error, Share};
+
+const SECRET: [u8; 16] = [
+    0xbb, 0x54, 0xaa, 0xc4, 0xb8, 0x9d, 0xc8, 0x68, 0xba, 0x37, 0xd9, 0xcc, 0x21, 0xb2, 0xce, 0xce,
+];
+
+#[test]
+fn test_split_produces_valid_mnemonics() {
+    let shares = split(&SECRET, b"", 3, 5).unwrap();
+    assert_eq!(shares.len(), 5);
+
+    for (i, share) in shares.iter().enumerate() {
+        let mnemonic = share.to_mnemonic().unwrap();
+        assert_eq!(mnemonic.split(' ').count(), 20);
+
+        let parsed = Share::from_mnemonic(&mnemonic).unwrap();
+        assert_eq!(&parsed, share);
+        assert_eq!(parsed.identifier, shares[0].identifier);
+        assert_eq!(parsed.member_index, i as u8);
+        assert_eq!(parsed.member_threshold, 3);
+        assert_eq!(parsed.group_threshold, 1);
+        assert_eq!(parsed.group_count, 1);
+        assert!(parsed.extendable);
+    }
+}
+
+#[test]
+fn test_split_256_bit_secret() {
+    let shares = split(&[0x42; 32], b"", 2, 3).unwrap();
+    for share in &shares {
+        assert_eq!(share.to_mnemonic().unwrap().split(' ').count(), 33);
+    }
+}
+
+#[test]
+fn test_split_rejects_invalid_secret_length() {
+    assert!(matches!(
+        split(&[0u8; 15], b"", 2, 3),
+        Err(Error::InvalidSecretLength(120))
+    ));
+    assert!(matches!(
+        split(&[0u8; 17], b"", 2, 3),
+        Err(Error::InvalidSecretLength(136))
+    ));
+}
+
+#[test]
+fn test_split_rejects_threshold_above_count() {
+    assert!(matches!(
+        split(&SECRET, b"", 4, 3),
+        Err(Error::InvalidParameters(_))
+    ));
+}
+
+#[test]
+fn test_split_groups_layout() {
+    let groups = split_groups(&SECRET, b"", false, 0, 2, &[(2, 3), (3, 5), (1, 1)]).unwrap();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0].len(), 3);
+    assert_eq!(groups[1].len(), 5);
+    assert_eq!(groups[2].len(), 1);
+
+    let identifier = groups[0][0].identifier;
+    for (group_index, shares) in groups.iter().enumerate() {
+        for share in shares {
+            let parsed = Share::from_mnemonic(&share.to_mnemonic().unwrap()).unwrap();
+            assert_eq!(parsed.identifier, identifier);
+            assert_eq!(parsed.group_index, group_index as u8);
+            assert_eq!(parsed.group_threshold, 2);
+            assert_eq!(parsed.group_count, 3);
+        }
+    }
+    assert_eq!(groups[1][0].member_threshold, 3);
+}
+
+#[test]
+fn test_split_groups_constraints() {
+    // Group threshold above group count
+    assert!(matches!(
+        split_groups(&SECRET, b"", false, 0, 3, &[(2, 3), (2, 3)]),
+        Err(Error::InvalidParameters(_))
+    ));
+    // Member threshold 1 implies member count 1
+    assert!(matches!(
+        split_groups(&SECRET, b"", false, 0, 1, &[(1, 3)]),
+        Err(Error::InvalidParameters(_))
+    ));
+    // At most 16 groups
+    assert!(matches!(
+        split_groups(&SECRET, b"", false, 0, 2, &[(1, 1); 17]),
+        Err(Error::InvalidParameters(_))
+    ));
+    // At least one group
+    assert!(matches!(
+        split_groups(&SECRET, b"", false, 0, 1, &[]),
+        Err(Error::InvalidParameters(_))
+    ));
+}
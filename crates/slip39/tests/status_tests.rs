@@ -0,0 +1,124 @@
+//! Recovery progress tests
+//!
+//! Verifies:
+//! - Shares are sorted into sets by identifier
+//! - Group and set progress matches what combine requires
+//! - Duplicates are ignored and contradicting shares are reported as conflicts
+//! - Group indices outside the set are rejected by status and combine alike
+
+use slip39_calculator::{
+    combine, combine_mnemonics, create_checksum, share_status, split, split_groups, Customization,
+    Error, Share, WordIndex,
+};
+
+const SECRET: [u8; 16] = [0x11; 16];
+
+#[test]
+fn test_progress_of_group_scheme() {
+    let groups = split_groups(&SECRET, b"", true, 0, 2, &[(2, 3), (3, 5), (1, 1)]).unwrap();
+
+    let mut shares = vec![
+        groups[0][0].clone(),
+        groups[1][1].clone(),
+        groups[1][4].clone(),
+    ];
+    let status = share_status(&shares);
+    assert_eq!(status.len(), 1);
+    let set = &status[0];
+    assert_eq!(set.identifier, groups[0][0].identifier);
+    assert_eq!(set.groups.len(), 2);
+    assert_eq!(set.groups[0].members_needed(), 1);
+    assert_eq!(set.groups[1].member_indices, vec![1, 4]);
+    assert_eq!(set.groups[1].members_needed(), 1);
+    assert_eq!(set.missing_groups(), vec![2]);
+    assert_eq!(set.groups_needed(), 2);
+    assert!(!set.is_recoverable());
+
+    shares.push(groups[2][0].clone());
+    shares.push(groups[0][2].clone());
+    let set = &share_status(&shares)[0];
+    assert_eq!(set.complete_groups(), 2);
+    assert!(set.is_recoverable());
+    assert_eq!(combine(&shares, b"").unwrap(), SECRET);
+}
+
+#[test]
+fn test_separate_share_sets() {
+    let first = split(&SECRET, b"", 2, 3).unwrap();
+    let mut second = split(&SECRET, b"", 3, 5).unwrap();
+    for share in &mut second {
+        share.identifier = first[0].identifier ^ 1;
+    }
+
+    let status = share_status(&[first[0].clone(), second[0].clone(), first[1].clone()]);
+    assert_eq!(status.len(), 2);
+    let (a, b) = if status[0].identifier == first[0].identifier {
+        (&status[0], &status[1])
+    } else {
+        (&status[1], &status[0])
+    };
+    assert!(a.is_recoverable());
+    assert_eq!(b.groups[0].members_needed(), 2);
+}
+
+#[test]
+fn test_duplicates_and_conflicts() {
+    let shares = split(&SECRET, b"", 2, 3).unwrap();
+    let set = &share_status(&[shares[0].clone(), shares[0].clone()])[0];
+    assert_eq!(set.groups[0].member_indices, vec![0]);
+    assert!(set.conflicts.is_empty());
+
+    let mut forged = shares[0].clone();
+    forged.value[0] ^= 1;
+    let mut other_threshold = shares[1].clone();
+    other_threshold.group_threshold = 2;
+    other_threshold.group_count = 2;
+    let set = &share_status(&[
+        shares[0].clone(),
+        forged,
+        other_threshold,
+        shares[2].clone(),
+    ])[0];
+    assert_eq!(set.conflicts.len(), 2);
+    assert_eq!(set.groups[0].member_indices, vec![0, 2]);
+    assert!(!set.is_recoverable());
+}
+
+#[test]
+fn test_group_index_outside_set() {
+    let shares = split(&SECRET, b"", 2, 3).unwrap();
+    let mut outside = shares[0].clone();
+    outside.group_index = 1;
+    let mixed = [outside, shares[1].clone()];
+
+    let set = &share_status(&mixed)[0];
+    assert_eq!(set.conflicts.len(), 1);
+    assert!(!set.is_recoverable());
+    assert!(matches!(combine(&mixed, b""), Err(Error::InvalidShare(_))));
+    assert!(matches!(mixed[0].to_indices(), Err(Error::InvalidShare(_))));
+
+    // The same share as a mnemonic: group index 1 lives in the top bits of the third word
+    let mut indices = shares[0].to_indices().unwrap();
+    indices[2] |= 1 << 6;
+    let data = indices.len() - 3;
+    let checksum = create_checksum(
+        &indices[..data],
+        Customization::from_extendable(shares[0].extendable),
+    );
+    indices[data..].copy_from_slice(&checksum);
+    assert!(matches!(
+        Share::from_indices(&indices),
+        Err(Error::InvalidShare(_))
+    ));
+
+    let words = indices
+        .iter()
+        .map(|&i| WordIndex::new(i).unwrap().word())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mnemonics = [words, shares[1].to_mnemonic().unwrap()];
+    assert!(matches!(
+        combine_mnemonics(&mnemonics, b""),
+        Err(Error::InvalidShare(_))
+    ));
+}
@@ -0,0 +1,74 @@
+//! Typo-tolerant suggestion tests
+//!
+//! Verifies:
+//! - Common misspellings rank the intended word first
+//! - Suggestions are limited in count and distance
+//! - Every wordlist entry is its own best suggestion
+//! - Lookup errors for unknown words carry the ranked suggestions
+
+use slip39_calculator::suggest::MAX_SUGGESTION_DISTANCE;
+use slip39_calculator::{suggest, wordlist, Error, Share, WordIndex};
+
+#[test]
+fn test_common_misspellings() {
+    for (typo, expected) in [
+        ("acedemic", "academic"),
+        ("Acadmeic", "academic"),
+        ("acdemic", "academic"),
+        ("keyborad", "keyboard"),
+        ("husbnad", "husband"),
+        ("zeor", "zero"),
+    ] {
+        let suggestions = suggest(typo, 3);
+        assert_eq!(suggestions[0].word, expected, "{}", typo);
+        assert_eq!(suggestions[0].distance, 1, "{}", typo);
+    }
+}
+
+#[test]
+fn test_suggestions_are_limited() {
+    let suggestions = suggest("aci", 4);
+    assert!(!suggestions.is_empty() && suggestions.len() <= 4);
+    assert!(suggestions
+        .iter()
+        .all(|s| s.distance <= MAX_SUGGESTION_DISTANCE));
+}
+
+#[test]
+fn test_exact_word_ranks_first() {
+    for &word in wordlist().iter().step_by(37) {
+        let suggestions = suggest(word, 1);
+        assert_eq!(suggestions[0].word, word);
+        assert_eq!(suggestions[0].distance, 0);
+    }
+}
+
+#[test]
+fn test_word_not_found_carries_suggestions() {
+    let err = WordIndex::from_prefix("acedemic").unwrap_err();
+    assert!(
+        matches!(&err, Error::WordNotFound { word, suggestions }
+            if word == "acedemic" && suggestions.first() == Some(&"academic")),
+        "{:?}",
+        err
+    );
+    assert!(
+        err.to_string().ends_with("(did you mean: academic?)"),
+        "{}",
+        err
+    );
+
+    let share = "duckling enlarge acedemic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+    assert!(matches!(
+        Share::from_mnemonic(share),
+        Err(Error::WordNotFound { suggestions, .. }) if suggestions.contains(&"academic")
+    ));
+
+    // No suggestions: the message is the bare error
+    let err = WordIndex::from_word_ct("zzzzzzzz").unwrap_err();
+    assert!(matches!(&err, Error::WordNotFound { suggestions, .. } if suggestions.is_empty()));
+    assert_eq!(
+        err.to_string(),
+        "Word 'zzzzzzzz' not found in SLIP-39 wordlist"
+    );
+}
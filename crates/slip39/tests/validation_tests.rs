@@ -36,7 +36,7 @@ fn test_encode_word_not_found_clear_message() {
     let result = encode("notaword");
     assert!(result.is_err());
 
-    if let Err(Error::WordNotFound(word)) = result {
+    if let Err(Error::WordNotFound { word, .. }) = result {
         assert_eq!(word, "notaword");
     } else {
         panic!("Expected WordNotFound error");
@@ -0,0 +1,208 @@
+//! SLIP-39 test vectors
+//!
+//! Runs `const/vectors.json`, the 40-vector revision of the official
+//! `vectors.json` from python-shamir-mnemonic (as shipped with sssmc39 0.0.3).
+//! Every entry is `[description, [mnemonics...], master_secret_hex, xprv?]`;
+//! the xprv column and the extendable vectors 41-45 only exist in the current
+//! upstream revision, which is not vendored yet.
+//!
+//! Verifies:
+//! - The vendored file matches its pinned SHA256 checksum
+//! - Every valid vector combines to the expected master secret (passphrase "TREZOR")
+//! - The BIP-32 master key of every valid vector matches its xprv, when present
+//! - Every invalid vector is rejected with the error category listed for it
+
+use sha2::{Digest, Sha256};
+use slip39_calculator::{combine_mnemonics, Error, ExtendedPrivateKey};
+use std::fs;
+
+/// SHA256 checksum of const/vectors.json
+/// If the vectors are updated intentionally, refresh it via `shasum -a 256 const/vectors.json`
+const VECTORS_SHA256: &str = "ce9e7378316b97f9902bc9372914ddcb1fa0922195cc46029b829fddff61cdd4";
+
+/// Passphrase used by all official test vectors
+const PASSPHRASE: &[u8] = b"TREZOR";
+
+/// Error categories of the invalid vectors
+#[derive(Debug, Clone, Copy)]
+enum Expected {
+    Checksum,
+    Padding,
+    MnemonicLength,
+    Digest,
+    InvalidShare,
+    Inconsistent,
+    Insufficient,
+}
+
+impl Expected {
+    fn matches(self, error: &Error) -> bool {
+        match self {
+            Expected::Checksum => matches!(error, Error::InvalidChecksum),
+            Expected::Padding => matches!(error, Error::InvalidPadding),
+            Expected::MnemonicLength => matches!(error, Error::InvalidMnemonicLength(_)),
+            Expected::Digest => matches!(error, Error::InvalidDigest),
+            Expected::InvalidShare => matches!(error, Error::InvalidShare(_)),
+            Expected::Inconsistent => matches!(error, Error::InconsistentShares(_)),
+            Expected::Insufficient => matches!(error, Error::InsufficientShares(_)),
+        }
+    }
+}
+
+/// Expected error of every invalid vector, keyed by its number in the file
+const INVALID_VECTORS: &[(usize, Expected)] = &[
+    (2, Expected::Checksum),
+    (3, Expected::Padding),
+    (5, Expected::Insufficient),
+    (6, Expected::Inconsistent),
+    (7, Expected::Inconsistent),
+    (8, Expected::Inconsistent),
+    (9, Expected::Inconsistent),
+    (10, Expected::InvalidShare),
+    (11, Expected::Inconsistent),
+    (12, Expected::Inconsistent),
+    (13, Expected::Digest),
+    (14, Expected::Insufficient),
+    (15, Expected::Insufficient),
+    (16, Expected::Insufficient),
+    (21, Expected::Checksum),
+    (22, Expected::Padding),
+    (24, Expected::Insufficient),
+    (25, Expected::Inconsistent),
+    (26, Expected::Inconsistent),
+    (27, Expected::Inconsistent),
+    (28, Expected::Inconsistent),
+    (29, Expected::InvalidShare),
+    (30, Expected::Inconsistent),
+    (31, Expected::Inconsistent),
+    (32, Expected::Digest),
+    (33, Expected::Insufficient),
+    (34, Expected::Insufficient),
+    (35, Expected::Insufficient),
+    (39, Expected::MnemonicLength),
+    (40, Expected::MnemonicLength),
+];
+
+struct Vector {
+    number: usize,
+    description: String,
+    mnemonics: Vec<String>,
+    master_secret: String,
+    xprv: Option<String>,
+}
+
+fn load_vectors() -> Vec<Vector> {
+    let content =
+        fs::read_to_string("const/vectors.json").expect("Failed to read const/vectors.json");
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(&content).expect("const/vectors.json is not valid JSON");
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| Vector {
+            number: i + 1,
+            description: entry[0].as_str().unwrap().to_string(),
+            mnemonics: entry[1]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|m| m.as_str().unwrap().to_string())
+                .collect(),
+            master_secret: entry[2].as_str().unwrap().to_string(),
+            xprv: entry.get(3).map(|x| x.as_str().unwrap().to_string()),
+        })
+        .collect()
+}
+
+/// The expected error of a vector, `None` for valid vectors
+fn expected_error(number: usize) -> Option<Expected> {
+    INVALID_VECTORS
+        .iter()
+        .find(|(n, _)| *n == number)
+        .map(|&(_, expected)| expected)
+}
+
+#[test]
+fn test_vectors_checksum() {
+    let content = fs::read("const/vectors.json").expect("Failed to read const/vectors.json");
+    let hash = format!("{:x}", Sha256::digest(&content));
+    assert_eq!(
+        hash, VECTORS_SHA256,
+        "Test vector checksum mismatch!\n\
+         Expected: {}\n\
+         Got:      {}\n\
+         \n\
+         If the vectors were updated intentionally, update VECTORS_SHA256.\n\
+         Otherwise, restore the file from git: git checkout const/vectors.json",
+        VECTORS_SHA256, hash
+    );
+}
+
+#[test]
+fn test_invalid_vector_table_matches_file() {
+    let vectors = load_vectors();
+    for vector in &vectors {
+        assert_eq!(
+            expected_error(vector.number).is_some(),
+            vector.master_secret.is_empty(),
+            "{}: listed in INVALID_VECTORS but has a master secret, or vice versa",
+            vector.description
+        );
+    }
+    assert!(INVALID_VECTORS
+        .iter()
+        .all(|&(number, _)| number <= vectors.len()));
+}
+
+#[test]
+fn test_valid_vectors() {
+    let vectors = load_vectors();
+    let valid: Vec<&Vector> = vectors
+        .iter()
+        .filter(|v| !v.master_secret.is_empty())
+        .collect();
+    assert!(!valid.is_empty());
+
+    for vector in valid {
+        let secret = combine_mnemonics(&vector.mnemonics, PASSPHRASE)
+            .unwrap_or_else(|e| panic!("{}: {}", vector.description, e));
+        assert_eq!(
+            hex::encode(&secret),
+            vector.master_secret,
+            "{}",
+            vector.description
+        );
+        if let Some(xprv) = &vector.xprv {
+            let master = ExtendedPrivateKey::from_seed(&secret).unwrap();
+            assert_eq!(&master.to_xprv(), xprv, "{}", vector.description);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_vectors() {
+    let vectors = load_vectors();
+    let invalid: Vec<(&Vector, Expected)> = vectors
+        .iter()
+        .filter_map(|v| expected_error(v.number).map(|expected| (v, expected)))
+        .collect();
+    assert_eq!(invalid.len(), INVALID_VECTORS.len());
+
+    for (vector, expected) in invalid {
+        match combine_mnemonics(&vector.mnemonics, PASSPHRASE) {
+            Ok(secret) => panic!(
+                "{}: expected an error, recovered {}",
+                vector.description,
+                hex::encode(secret)
+            ),
+            Err(e) => assert!(
+                expected.matches(&e),
+                "{}: expected {:?}, got {:?}",
+                vector.description,
+                expected,
+                e
+            ),
+        }
+    }
+}